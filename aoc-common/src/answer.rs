@@ -0,0 +1,124 @@
+use std::fmt;
+
+use crate::{grid::Grid, parse::Parser};
+
+/// What a part returns, whatever the day.
+///
+/// Displayed as is: numbers in decimal, text unchanged and images as their rows separated by
+/// newlines, without leading or trailing newline.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    /// Also the integers too big for an `i64`, in decimal, so they are still shown as is.
+    Text(String),
+    /// Letters drawn with `#` and `.`, like day 10 part 2.
+    Image(Grid<char>),
+    /// For the inputs which have no solution, like a day 15 beacon which is nowhere, and for the
+    /// parts without a puzzle, like day 25 part 2.
+    Nothing,
+}
+
+impl Answer {
+    /// Numbers stay numbers, images become arrays of rows and nothing is `null`.
+    pub fn to_json(&self) -> serde_json::Value {
+        match self {
+            Answer::Int(n) => (*n).into(),
+            Answer::Text(s) => s.as_str().into(),
+            Answer::Image(g) => (0..g.height())
+                .map(|y| g.row(y).collect::<String>())
+                .collect(),
+            Answer::Nothing => serde_json::Value::Null,
+        }
+    }
+
+    /// Back from [`Answer::to_json`], `None` for the values it never gives.
+    pub fn from_json(v: &serde_json::Value) -> Option<Self> {
+        use serde_json::Value;
+        Some(match v {
+            Value::Number(n) => Answer::Int(n.as_i64()?),
+            Value::String(s) => Answer::Text(s.clone()),
+            Value::Array(rows) => {
+                let rows = rows
+                    .iter()
+                    .map(Value::as_str)
+                    .collect::<Option<Vec<_>>>()?
+                    .join("\n");
+                let p = Parser::new(0, &rows);
+                Answer::Image(Grid::parse(&p, &rows, "a pixel", Some).ok()?)
+            }
+            Value::Null => Answer::Nothing,
+            _ => return None,
+        })
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Image(g) => write!(f, "{}", g),
+            Answer::Nothing => write!(f, "no answer"),
+        }
+    }
+}
+
+macro_rules! from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                /// [`Answer::Text`] when `n` does not fit in an `i64`.
+                fn from(n: $t) -> Self {
+                    i64::try_from(n).map_or_else(|_| Answer::Text(n.to_string()), Answer::Int)
+                }
+            }
+        )*
+    };
+}
+
+from_int!(u32, u64, usize, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(a: Option<T>) -> Self {
+        a.map_or(Answer::Nothing, Into::into)
+    }
+}
+
+impl From<Grid<char>> for Answer {
+    fn from(g: Grid<char>) -> Self {
+        Answer::Image(g)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_and_json() {
+        let image = Answer::from(Grid::new(2, 2, '#'));
+        assert_eq!(image.to_string(), "##\n##");
+        assert_eq!(image.to_json(), serde_json::json!(["##", "##"]));
+        assert_eq!(Answer::from(42usize).to_json(), serde_json::json!(42));
+        assert_eq!(Answer::from("A-1".to_owned()).to_string(), "A-1");
+        assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(
+            Answer::from(None::<usize>).to_json(),
+            serde_json::Value::Null
+        );
+        for answer in [
+            image,
+            Answer::Int(-3),
+            Answer::Text("7".into()),
+            Answer::Nothing,
+        ] {
+            assert_eq!(Answer::from_json(&answer.to_json()), Some(answer));
+        }
+    }
+}
@@ -0,0 +1,31 @@
+//! Parsed inputs written out, to see what a parser made of an input or to compare two versions
+//! of it. This needs the `serde` feature, without which [`Dump`] is implemented by every type.
+
+#[cfg(feature = "serde")]
+use std::collections::HashMap;
+
+/// What the parsed inputs implement: [`serde::Serialize`] with the `serde` feature.
+#[cfg(feature = "serde")]
+pub trait Dump: serde::Serialize {}
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> Dump for T {}
+
+/// What the parsed inputs implement: nothing without the `serde` feature.
+#[cfg(not(feature = "serde"))]
+pub trait Dump {}
+#[cfg(not(feature = "serde"))]
+impl<T> Dump for T {}
+
+/// For the maps whose keys are not strings, with `#[serde(serialize_with = "...")]`: a list of
+/// `[key, value]`, sorted so that the same input always gives the same dump.
+#[cfg(feature = "serde")]
+pub fn sorted_pairs<K, V, S>(map: &HashMap<K, V>, s: S) -> Result<S::Ok, S::Error>
+where
+    K: serde::Serialize + Ord,
+    V: serde::Serialize,
+    S: serde::Serializer,
+{
+    let mut pairs: Vec<_> = map.iter().collect();
+    pairs.sort_by_key(|&(k, _)| k);
+    s.collect_seq(pairs)
+}
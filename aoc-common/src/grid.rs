@@ -0,0 +1,279 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    geom::{Dir4, Dir8, Point2},
+    parse::{ParseError, Parser},
+};
+
+/// Position in a [`Grid`], as `(x, y)`: column then row.
+pub type Pos = (usize, usize);
+
+/// Dense 2D grid stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Grid<T> {
+    w: usize,
+    h: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(w: usize, h: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            w,
+            h,
+            cells: vec![fill; w * h],
+        }
+    }
+
+    /// Parses a rectangular, non-empty character map, `f` returns `None` for unexpected
+    /// characters.
+    pub fn parse(
+        p: &Parser,
+        input: &str,
+        expected: &str,
+        f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError>
+    where
+        T: Clone,
+    {
+        Self::parse_lines(p, input, None, expected, f)
+    }
+
+    /// Same as [`Grid::parse`] but shorter lines are padded with `pad` instead of rejected.
+    pub fn parse_padded(
+        p: &Parser,
+        input: &str,
+        pad: T,
+        expected: &str,
+        f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError>
+    where
+        T: Clone,
+    {
+        Self::parse_lines(p, input, Some(pad), expected, f)
+    }
+
+    fn parse_lines(
+        p: &Parser,
+        input: &str,
+        pad: Option<T>,
+        expected: &str,
+        mut f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError>
+    where
+        T: Clone,
+    {
+        let w = match pad {
+            Some(_) => input.lines().map(|l| l.chars().count()).max(),
+            None => input.lines().next().map(|l| l.chars().count()),
+        }
+        .unwrap_or(0);
+        let mut cells = Vec::new();
+        for l in input.lines() {
+            let start = cells.len();
+            for (i, c) in l.char_indices() {
+                cells.push(f(c).ok_or_else(|| p.error(&l[i..], expected))?);
+            }
+            match &pad {
+                _ if cells.len() - start == w => (),
+                Some(pad) if cells.len() - start < w => cells.resize(start + w, pad.clone()),
+                _ => return Err(p.error(l, format!("a row of {} cells", w))),
+            }
+        }
+        if cells.is_empty() {
+            return Err(p.error(input, expected));
+        }
+        Ok(Grid {
+            w,
+            h: cells.len().checked_div(w).unwrap_or(0),
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.w
+    }
+
+    pub fn height(&self) -> usize {
+        self.h
+    }
+
+    pub fn contains(&self, (x, y): Pos) -> bool {
+        x < self.w && y < self.h
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.1 * self.w + pos.0])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        match self.contains(pos) {
+            true => Some(&mut self.cells[pos.1 * self.w + pos.0]),
+            false => None,
+        }
+    }
+
+    /// Resizes the grid to `w` by `h`, keeping the cells at the same positions and filling the
+    /// new ones with `fill`.
+    pub fn resize(&mut self, w: usize, h: usize, fill: T)
+    where
+        T: Clone,
+    {
+        if w != self.w {
+            let mut cells = Vec::with_capacity(w * self.h);
+            for y in 0..self.h {
+                cells.extend(self.row(y).take(w).cloned());
+                cells.resize((y + 1) * w, fill.clone());
+            }
+            self.cells = cells;
+            self.w = w;
+        }
+        self.cells.resize(w * h, fill);
+        self.h = h;
+    }
+
+    /// Copy of the grid with `n` cells of `fill` added on each side.
+    pub fn padded(&self, n: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        let mut g = Grid::new(self.w + 2 * n, self.h + 2 * n, fill);
+        for (pos, v) in self.iter() {
+            g[(pos.0 + n, pos.1 + n)] = v.clone();
+        }
+        g
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            w: self.w,
+            h: self.h,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let w = self.w;
+        (0..self.h).flat_map(move |y| (0..w).map(move |x| (x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn find(&self, mut f: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.iter().find(|(_, v)| f(v)).map(|(pos, _)| pos)
+    }
+
+    pub fn row(&self, y: usize) -> impl DoubleEndedIterator<Item = &T> {
+        self.cells[y * self.w..(y + 1) * self.w].iter()
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        self.cells[x..].iter().step_by(self.w)
+    }
+
+    /// `pos` moved by `d`, if it is still inside the grid.
+    pub fn offset(&self, pos: Pos, d: Point2) -> Option<Pos> {
+        let x = pos.0.checked_add_signed(d.x)?;
+        let y = pos.1.checked_add_signed(d.y)?;
+        self.contains((x, y)).then_some((x, y))
+    }
+
+    /// Positions from `pos` (excluded) going in direction `d`, until the edge of the grid.
+    pub fn ray(&self, pos: Pos, d: Point2) -> impl Iterator<Item = Pos> + '_ {
+        std::iter::successors(self.offset(pos, d), move |&p| self.offset(p, d))
+    }
+
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Dir4::ALL
+            .into_iter()
+            .filter_map(move |d| self.offset(pos, d.delta()))
+    }
+
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Dir8::ALL
+            .into_iter()
+            .filter_map(move |d| self.offset(pos, d.delta()))
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        assert!(self.contains(pos), "{:?} is outside the grid", pos);
+        &self.cells[pos.1 * self.w + pos.0]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        assert!(self.contains(pos), "{:?} is outside the grid", pos);
+        &mut self.cells[pos.1 * self.w + pos.0]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for y in 0..self.h {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for v in self.row(y) {
+                write!(f, "{}", v)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const INPUT: &str = "123\n456";
+
+    fn grid() -> Grid<u32> {
+        Grid::parse(&Parser::new(0, INPUT), INPUT, "a digit", |c| c.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn parse_and_display() {
+        let g = grid();
+        assert_eq!((g.width(), g.height()), (3, 2));
+        assert_eq!(g[(2, 1)], 6);
+        assert_eq!(g.to_string(), INPUT);
+        let input = "12\n3";
+        let p = Parser::new(0, input);
+        let e = Grid::parse(&p, input, "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((e.line, e.expected.as_str()), (2, "a row of 2 cells"));
+        let g = Grid::parse_padded(&p, input, 0, "a digit", |c| c.to_digit(10)).unwrap();
+        assert_eq!(g.to_string(), "12\n30");
+    }
+
+    #[test]
+    fn iterators() {
+        let g = grid();
+        assert_eq!(g.row(1).copied().collect::<Vec<_>>(), [4, 5, 6]);
+        assert_eq!(g.column(1).copied().collect::<Vec<_>>(), [2, 5]);
+        assert_eq!(
+            g.ray((0, 0), Dir4::Right.delta()).collect::<Vec<_>>(),
+            [(1, 0), (2, 0)]
+        );
+        assert_eq!(g.neighbours4((0, 0)).count(), 2);
+        assert_eq!(g.neighbours8((1, 0)).count(), 5);
+        assert_eq!(g.padded(1, 0).to_string(), "00000\n01230\n04560\n00000");
+        let mut g = g;
+        g.resize(2, 3, 0);
+        assert_eq!(g.to_string(), "12\n45\n00");
+    }
+}
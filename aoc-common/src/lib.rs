@@ -0,0 +1,19 @@
+//! What the solutions of every year share: the grids, geometry, parsing and search helpers, and
+//! the traits through which the runner finds and runs the days.
+
+#![warn(clippy::all)]
+
+pub mod answer;
+pub mod dump;
+pub mod geom;
+pub mod grid;
+pub mod normalise;
+pub mod params;
+pub mod parse;
+pub mod profile;
+pub mod results;
+pub mod search;
+pub mod simulation;
+pub mod solution;
+pub mod stream;
+pub mod viz;
@@ -0,0 +1,67 @@
+//! Inputs as the generators expect them, whatever the editor they were saved with: no byte order
+//! mark, `\n` line endings and nothing after the last line.
+
+use std::borrow::Cow;
+
+const BOM: char = '\u{feff}';
+
+/// Whitespace a day can do without.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trim {
+    /// The whitespace at the end of every line, and the blank lines at the end.
+    Lines,
+    /// Only the lines at the end which are empty or whitespace, for the days where the spaces
+    /// ending a line are part of a drawing.
+    End,
+}
+
+impl Trim {
+    fn line(self, l: &str) -> &str {
+        match self {
+            Trim::Lines => l.trim_end(),
+            Trim::End => l.strip_suffix('\r').unwrap_or(l),
+        }
+    }
+}
+
+/// Length of `s` without the lines at its end which are empty or whitespace.
+fn without_blank_end(s: &str) -> usize {
+    let text = s.trim_end().len();
+    match text {
+        0 => 0,
+        _ => s[text..].find('\n').map_or(s.len(), |i| text + i),
+    }
+}
+
+/// `input` without its byte order mark, with `\n` line endings and trimmed as `trim` says.
+/// Borrowed when there is nothing to change.
+pub fn normalise(input: &str, trim: Trim) -> Cow<'_, str> {
+    let input = input.strip_prefix(BOM).unwrap_or(input);
+    let lines_done = trim == Trim::End || input.split('\n').all(|l| l.trim_end() == l);
+    if lines_done && !input.contains('\r') && without_blank_end(input) == input.len() {
+        return Cow::Borrowed(input);
+    }
+    let mut s = String::with_capacity(input.len());
+    for l in input.split('\n') {
+        s.push_str(trim.line(l));
+        s.push('\n');
+    }
+    s.truncate(without_blank_end(&s));
+    Cow::Owned(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn windows() {
+        let input = "\u{feff}a b \r\n\r\n  c\t\r\n\r\n";
+        assert_eq!(normalise(input, Trim::Lines), "a b\n\n  c");
+        assert_eq!(normalise(input, Trim::End), "a b \n\n  c\t");
+        assert_eq!(normalise("  a\n\n b ", Trim::End), "  a\n\n b ");
+        assert_eq!(normalise("  a\n\n b \n  \r\n\t", Trim::End), "  a\n\n b ");
+        assert_eq!(normalise(" \n\t", Trim::End), "");
+        assert!(matches!(normalise("a\nb", Trim::Lines), Cow::Borrowed(_)));
+    }
+}
@@ -0,0 +1,178 @@
+use std::{any::Any, error::Error, fmt};
+
+/// Why a parameter could not be set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParamError {
+    Unknown {
+        name: String,
+        known: Vec<&'static str>,
+    },
+    Invalid {
+        name: String,
+        value: String,
+    },
+}
+
+impl fmt::Display for ParamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParamError::Unknown { name, known } if known.is_empty() => {
+                write!(f, "unknown parameter `{}`, this day has none", name)
+            }
+            ParamError::Unknown { name, known } => write!(
+                f,
+                "unknown parameter `{}`, expected one of {}",
+                name,
+                known.join(", ")
+            ),
+            ParamError::Invalid { name, value } => {
+                write!(f, "invalid value {:?} for parameter `{}`", value, name)
+            }
+        }
+    }
+}
+
+impl Error for ParamError {}
+
+/// Type of a parameter, written as text on the command line or in a config file.
+pub trait Value: Sized {
+    fn parse(s: &str) -> Option<Self>;
+    fn show(&self) -> String;
+}
+
+macro_rules! int_values {
+    ($($t:ty),*) => {
+        $(
+            impl Value for $t {
+                fn parse(s: &str) -> Option<Self> {
+                    s.trim().parse().ok()
+                }
+                fn show(&self) -> String {
+                    self.to_string()
+                }
+            }
+        )*
+    };
+}
+
+int_values!(u8, u32, u64, usize, i32, i64, isize);
+
+/// Comma separated.
+impl<T: Value> Value for Vec<T> {
+    fn parse(s: &str) -> Option<Self> {
+        s.split(',').map(T::parse).collect()
+    }
+    fn show(&self) -> String {
+        self.iter().map(T::show).collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Constants of a puzzle, which can be overridden by name.
+pub trait Params: Any {
+    fn set(&mut self, name: &str, value: &str) -> Result<(), ParamError>;
+    /// Names and current values of all the parameters.
+    fn values(&self) -> Vec<(&'static str, String)>;
+    fn as_any(&self) -> &dyn Any;
+}
+
+/// For the days without parameters.
+impl Params for () {
+    fn set(&mut self, name: &str, _: &str) -> Result<(), ParamError> {
+        Err(ParamError::Unknown {
+            name: name.to_owned(),
+            known: Vec::new(),
+        })
+    }
+    fn values(&self) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Declares the `Params` struct of a day, with the default value of each field.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __params {
+    ($(#[$m:meta])* pub struct $name:ident {
+        $($(#[$fm:meta])* pub $field:ident: $t:ty = $default:expr,)*
+    }) => {
+        $(#[$m])*
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub struct $name {
+            $($(#[$fm])* pub $field: $t,)*
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                $name {
+                    $($field: $default,)*
+                }
+            }
+        }
+
+        impl $crate::params::Params for $name {
+            fn set(&mut self, name: &str, value: &str) -> Result<(), $crate::params::ParamError> {
+                match name {
+                    $(stringify!($field) => {
+                        self.$field = $crate::params::Value::parse(value).ok_or_else(|| {
+                            $crate::params::ParamError::Invalid {
+                                name: name.to_owned(),
+                                value: value.to_owned(),
+                            }
+                        })?
+                    })*
+                    _ => {
+                        return Err($crate::params::ParamError::Unknown {
+                            name: name.to_owned(),
+                            known: vec![$(stringify!($field)),*],
+                        })
+                    }
+                }
+                Ok(())
+            }
+            fn values(&self) -> Vec<(&'static str, String)> {
+                vec![$((stringify!($field), $crate::params::Value::show(&self.$field))),*]
+            }
+            fn as_any(&self) -> &dyn std::any::Any {
+                self
+            }
+        }
+    };
+}
+pub use __params as params;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    params! {
+        pub struct Test {
+            pub n: usize = 3,
+            pub list: Vec<isize> = vec![1, -2],
+        }
+    }
+
+    #[test]
+    fn set() {
+        let mut p = Test::default();
+        assert_eq!(
+            p.values(),
+            [("n", "3".to_owned()), ("list", "1,-2".to_owned())]
+        );
+        p.set("n", "10").unwrap();
+        p.set("list", "4, 5").unwrap();
+        assert_eq!((p.n, p.list), (10, vec![4, 5]));
+        let mut p = Test::default();
+        assert_eq!(
+            p.set("m", "1").unwrap_err().to_string(),
+            "unknown parameter `m`, expected one of n, list"
+        );
+        assert_eq!(
+            p.set("n", "-1").unwrap_err().to_string(),
+            "invalid value \"-1\" for parameter `n`"
+        );
+        assert!(().set("n", "1").is_err());
+    }
+}
@@ -0,0 +1,113 @@
+use std::{error::Error, fmt, str::FromStr};
+
+/// Where and why an input could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    /// 1-based
+    pub line: usize,
+    /// 1-based, in characters
+    pub column: usize,
+    pub expected: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "day {}, line {}, column {}: expected {}",
+            self.day, self.line, self.column, self.expected
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Builds [`ParseError`]s pointing inside the input of a day.
+///
+/// All the `&str` given to its methods must be slices of the input it was created with, that's
+/// how the line and column are found back.
+#[derive(Debug, Clone, Copy)]
+pub struct Parser<'a> {
+    day: u8,
+    input: &'a str,
+    /// Lines of the whole input before `input`.
+    lines_before: usize,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(day: u8, input: &'a str) -> Self {
+        Parser::at_line(day, input, 1)
+    }
+
+    /// For a part of the input starting at the 1-based `line`, such as a line read from a stream.
+    pub fn at_line(day: u8, input: &'a str, line: usize) -> Self {
+        Parser {
+            day,
+            input,
+            lines_before: line - 1,
+        }
+    }
+
+    pub fn lines(&self) -> std::str::Lines<'a> {
+        self.input.lines()
+    }
+
+    /// The empty slice at the end of `s`, to report something missing.
+    pub fn end(s: &str) -> &str {
+        &s[s.len()..]
+    }
+
+    /// `s` without its first `n` bytes (or the end of `s` if it is too short).
+    pub fn skip(s: &str, n: usize) -> &str {
+        s.get(n..).unwrap_or_else(|| Self::end(s))
+    }
+
+    pub fn error(&self, at: &str, expected: impl Into<String>) -> ParseError {
+        let start = self.input.as_ptr() as usize;
+        let offset = (at.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|&o| o <= self.input.len())
+            .unwrap_or(self.input.len());
+        let before = &self.input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        ParseError {
+            day: self.day,
+            line: self.lines_before + before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            expected: expected.into(),
+        }
+    }
+
+    pub fn parse<T: FromStr>(&self, s: &str, expected: &str) -> Result<T, ParseError> {
+        s.parse().map_err(|_| self.error(s, expected))
+    }
+
+    pub fn split_once(
+        &self,
+        s: &'a str,
+        delimiter: &str,
+        expected: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        s.split_once(delimiter)
+            .ok_or_else(|| self.error(Self::end(s), expected))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn position() {
+        let input = "abc\ndéf\nghi";
+        let p = Parser::new(1, input);
+        let e = p.error(&input[7..], "x");
+        assert_eq!((e.line, e.column), (2, 3));
+        let e = p.error(Parser::end(input), "x");
+        assert_eq!((e.line, e.column), (3, 4));
+        assert_eq!(p.error("elsewhere", "x").line, 3);
+        let p = Parser::at_line(1, input, 5);
+        assert_eq!(p.error(&input[7..], "x").line, 6);
+    }
+}
@@ -0,0 +1,186 @@
+//! Where the time and memory of a day go, phase by phase.
+//!
+//! The memory is counted by [`Counting`], which has to be the global allocator of the binary for
+//! the counts not to stay at zero. The counters are shared by all the threads, so a day is only
+//! measured right when nothing else runs at the same time.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering::Relaxed},
+    time::{Duration, Instant},
+};
+
+use crate::{params::Params, parse::ParseError, solution::Solver};
+
+static IN_USE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+static TOTAL: AtomicUsize = AtomicUsize::new(0);
+
+/// The system allocator, counting the bytes allocated and in use.
+pub struct Counting;
+
+impl Counting {
+    fn grow(size: usize) {
+        TOTAL.fetch_add(size, Relaxed);
+        let in_use = IN_USE.fetch_add(size, Relaxed) + size;
+        PEAK.fetch_max(in_use, Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let p = System.alloc(layout);
+        if !p.is_null() {
+            Self::grow(layout.size());
+        }
+        p
+    }
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let p = System.alloc_zeroed(layout);
+        if !p.is_null() {
+            Self::grow(layout.size());
+        }
+        p
+    }
+    unsafe fn dealloc(&self, p: *mut u8, layout: Layout) {
+        System.dealloc(p, layout);
+        IN_USE.fetch_sub(layout.size(), Relaxed);
+    }
+    /// Counted as a new allocation of the new size, which is what a move costs.
+    unsafe fn realloc(&self, p: *mut u8, layout: Layout, size: usize) -> *mut u8 {
+        let new = System.realloc(p, layout, size);
+        if !new.is_null() {
+            IN_USE.fetch_sub(layout.size(), Relaxed);
+            Self::grow(size);
+        }
+        new
+    }
+}
+
+/// Memory of a phase.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Usage {
+    /// Most bytes in use at once, counted from the start of the day, so the parsed input is
+    /// part of the peak of both parts.
+    pub peak: usize,
+    /// Bytes allocated, even if freed right after.
+    pub allocated: usize,
+}
+
+/// Time and memory of each phase of a day: parsing, part 1 and part 2.
+#[derive(Debug, Clone)]
+pub struct DayProfile {
+    pub year: u16,
+    pub day: u8,
+    pub times: [Duration; 3],
+    pub usage: [Usage; 3],
+}
+
+/// Runs `f`, with what it allocated and the peak of the bytes in use above `base`.
+fn measure<T>(base: usize, f: impl FnOnce() -> T) -> (T, Duration, Usage) {
+    PEAK.store(IN_USE.load(Relaxed), Relaxed);
+    let total = TOTAL.load(Relaxed);
+    let start = Instant::now();
+    let res = f();
+    let time = start.elapsed();
+    let usage = Usage {
+        peak: PEAK.load(Relaxed).saturating_sub(base),
+        allocated: TOTAL.load(Relaxed) - total,
+    };
+    (res, time, usage)
+}
+
+pub fn profile_day(
+    year: u16,
+    solver: &dyn Solver,
+    input: &str,
+    params: &dyn Params,
+) -> Result<DayProfile, ParseError> {
+    let base = IN_USE.load(Relaxed);
+    let (parsed, parse, parse_usage) = measure(base, || solver.parse(input));
+    let parsed = parsed?;
+    // the answers are dropped inside, they are not part of the solution
+    let ((), time1, usage1) = measure(base, || drop(solver.part1(parsed.as_ref(), params)));
+    let ((), time2, usage2) = measure(base, || drop(solver.part2(parsed.as_ref(), params)));
+    Ok(DayProfile {
+        year,
+        day: solver.day(),
+        times: [parse, time1, time2],
+        usage: [parse_usage, usage1, usage2],
+    })
+}
+
+/// In binary units, with three significant digits at most.
+fn bytes(n: usize) -> String {
+    let units = ["B", "KiB", "MiB", "GiB", "TiB"];
+    let mut size = n as f64;
+    let mut unit = 0;
+    while size >= 1024. && unit + 1 < units.len() {
+        size /= 1024.;
+        unit += 1;
+    }
+    match unit {
+        0 => format!("{} B", n),
+        _ if size >= 100. => format!("{:.0} {}", size, units[unit]),
+        _ if size >= 10. => format!("{:.1} {}", size, units[unit]),
+        _ => format!("{:.2} {}", size, units[unit]),
+    }
+}
+
+/// A row per day, with the peak and the allocated bytes of each phase.
+pub fn to_markdown(profiles: &[DayProfile]) -> String {
+    let mut s = "| year | day | parse | part1 | part2 | parse peak | part1 peak | part2 peak \
+                 | parse alloc | part1 alloc | part2 alloc |\n"
+        .to_owned();
+    s.push_str(
+        "|-----:|----:|------:|------:|------:|-----------:|-----------:|-----------:\
+         |------------:|------------:|------------:|\n",
+    );
+    for p in profiles {
+        let [parse, part1, part2] = p.usage;
+        s.push_str(&format!(
+            "| {} | {} | {:.2?} | {:.2?} | {:.2?} | {} | {} | {} | {} | {} | {} |\n",
+            p.year,
+            p.day,
+            p.times[0],
+            p.times[1],
+            p.times[2],
+            bytes(parse.peak),
+            bytes(part1.peak),
+            bytes(part2.peak),
+            bytes(parse.allocated),
+            bytes(part1.allocated),
+            bytes(part2.allocated),
+        ));
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[global_allocator]
+    static ALLOC: Counting = Counting;
+
+    #[test]
+    fn counts() {
+        // other tests allocate at the same time, hence the lower bounds
+        let base = IN_USE.load(Relaxed);
+        let (v, _, usage) = measure(base, || vec![0_u8; 1 << 20]);
+        assert!(usage.peak >= 1 << 20 && usage.allocated >= 1 << 20);
+        let ((), _, usage) = measure(base, || {
+            let mut v = v;
+            v.resize(2 << 20, 1);
+        });
+        assert!(usage.peak >= 2 << 20 && usage.allocated >= 2 << 20);
+    }
+
+    #[test]
+    fn units() {
+        assert_eq!(bytes(1000), "1000 B");
+        assert_eq!(bytes(1536), "1.50 KiB");
+        assert_eq!(bytes(20 << 20), "20.0 MiB");
+        assert_eq!(bytes(300 << 30), "300 GiB");
+    }
+}
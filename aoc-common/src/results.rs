@@ -0,0 +1,113 @@
+use std::{
+    any::Any,
+    time::{Duration, Instant},
+};
+
+use serde_json::json;
+
+use crate::{answer::Answer, params::Params, parse::ParseError, solution::Solver};
+
+/// Answers of a day with the time taken by each step.
+#[derive(Debug, Clone)]
+pub struct DayResult {
+    pub year: u16,
+    pub day: u8,
+    pub answers: [Answer; 2],
+    pub parse: Duration,
+    pub parts: [Duration; 2],
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let res = f();
+    (res, start.elapsed())
+}
+
+pub fn run_day(
+    year: u16,
+    solver: &dyn Solver,
+    input: &str,
+    params: &dyn Params,
+) -> Result<DayResult, ParseError> {
+    let (parsed, parse) = timed(|| solver.parse(input));
+    let parsed = parsed?;
+    let (part1, time1) = timed(|| solver.part1(parsed.as_ref(), params));
+    let (part2, time2) = timed(|| solver.part2(parsed.as_ref(), params));
+    Ok(DayResult {
+        year,
+        day: solver.day(),
+        answers: [part1, part2],
+        parse,
+        parts: [time1, time2],
+    })
+}
+
+/// Answer and time of every implementation of `part` on the parsed `input`, the main one first
+/// and named `main`.
+pub fn run_variants(
+    solver: &dyn Solver,
+    input: &dyn Any,
+    params: &dyn Params,
+    part: u8,
+) -> Vec<(&'static str, Answer, Duration)> {
+    let (main, time) = timed(|| match part {
+        1 => solver.part1(input, params),
+        _ => solver.part2(input, params),
+    });
+    let mut runs = vec![("main", main, time)];
+    for name in solver.variants(part) {
+        let (answer, time) = timed(|| solver.variant(part, name, input, params).unwrap());
+        runs.push((name, answer, time));
+    }
+    runs
+}
+
+fn ms(d: Duration) -> f64 {
+    d.as_secs_f64() * 1000.
+}
+
+/// One object per day, timings in milliseconds.
+pub fn to_json(results: &[DayResult]) -> serde_json::Value {
+    results
+        .iter()
+        .map(|r| {
+            json!({
+                "year": r.year,
+                "day": r.day,
+                "part1": r.answers[0].to_json(),
+                "part2": r.answers[1].to_json(),
+                "parse_ms": ms(r.parse),
+                "part1_ms": ms(r.parts[0]),
+                "part2_ms": ms(r.parts[1]),
+            })
+        })
+        .collect()
+}
+
+/// Images are put on several lines inside their cell.
+fn cell(a: &Answer) -> String {
+    match a {
+        Answer::Image(_) => format!("<pre>{}</pre>", a.to_string().replace('\n', "<br>")),
+        a => format!("`{}`", a),
+    }
+}
+
+pub fn to_markdown(results: &[DayResult]) -> String {
+    let mut s =
+        "| year | day | part1 | part2 | parse | part1 time | part2 time | total |\n".to_owned();
+    s.push_str("|-----:|----:|-------|-------|------:|-----------:|-----------:|------:|\n");
+    for r in results {
+        s.push_str(&format!(
+            "| {} | {} | {} | {} | {:.2?} | {:.2?} | {:.2?} | {:.2?} |\n",
+            r.year,
+            r.day,
+            cell(&r.answers[0]),
+            cell(&r.answers[1]),
+            r.parse,
+            r.parts[0],
+            r.parts[1],
+            r.parse + r.parts[0] + r.parts[1]
+        ));
+    }
+    s
+}
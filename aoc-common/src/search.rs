@@ -0,0 +1,166 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+};
+
+/// A graph only known through the successors of its nodes.
+pub trait Graph {
+    type Node: Clone + Eq + Hash;
+
+    /// Nodes reachable from `node` in one step, with the cost of that step ([`bfs`] counts every
+    /// step as 1).
+    fn successors(&self, node: &Self::Node) -> Vec<(Self::Node, usize)>;
+
+    fn is_goal(&self, node: &Self::Node) -> bool;
+
+    /// Lower bound of the cost from `node` to a goal, only used by [`astar`].
+    fn heuristic(&self, _node: &Self::Node) -> usize {
+        0
+    }
+}
+
+/// What a search found: every node reached, and the first goal if there was one.
+#[derive(Debug, Clone)]
+pub struct Search<N> {
+    /// Distance from the start and the previous node on the path.
+    reached: HashMap<N, (usize, Option<N>)>,
+    pub goal: Option<N>,
+}
+
+impl<N: Clone + Eq + Hash> Search<N> {
+    fn new(start: N) -> Self {
+        Search {
+            reached: HashMap::from([(start, (0, None))]),
+            goal: None,
+        }
+    }
+
+    pub fn distance(&self, node: &N) -> Option<usize> {
+        self.reached.get(node).map(|&(d, _)| d)
+    }
+
+    /// Nodes from the start to `node`, both included.
+    pub fn path(&self, node: &N) -> Option<Vec<N>> {
+        let mut path = vec![node.clone()];
+        let mut prev = &self.reached.get(node)?.1;
+        while let Some(n) = prev {
+            path.push(n.clone());
+            prev = &self.reached[n].1;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    pub fn goal_distance(&self) -> Option<usize> {
+        self.distance(self.goal.as_ref()?)
+    }
+
+    pub fn goal_path(&self) -> Option<Vec<N>> {
+        self.path(self.goal.as_ref()?)
+    }
+
+    pub fn reached(&self) -> impl Iterator<Item = (&N, usize)> {
+        self.reached.iter().map(|(n, &(d, _))| (n, d))
+    }
+}
+
+/// Breadth-first search, stopping at the first goal (or once everything reachable was visited).
+pub fn bfs<G: Graph>(g: &G, start: G::Node) -> Search<G::Node> {
+    let mut s = Search::new(start.clone());
+    let mut queue = VecDeque::from([start]);
+    while let Some(n) = queue.pop_front() {
+        if g.is_goal(&n) {
+            s.goal = Some(n);
+            break;
+        }
+        let d = s.reached[&n].0;
+        for (m, _) in g.successors(&n) {
+            if !s.reached.contains_key(&m) {
+                s.reached.insert(m.clone(), (d + 1, Some(n.clone())));
+                queue.push_back(m);
+            }
+        }
+    }
+    s
+}
+
+pub fn dijkstra<G: Graph>(g: &G, start: G::Node) -> Search<G::Node> {
+    best_first(g, start, |_| 0)
+}
+
+pub fn astar<G: Graph>(g: &G, start: G::Node) -> Search<G::Node> {
+    best_first(g, start, |n| g.heuristic(n))
+}
+
+fn best_first<G: Graph>(
+    g: &G,
+    start: G::Node,
+    heuristic: impl Fn(&G::Node) -> usize,
+) -> Search<G::Node> {
+    let mut s = Search::new(start.clone());
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), 0, 0))]);
+    // the heap only holds indices in there, so nodes don't have to be `Ord`
+    let mut nodes = vec![start];
+    while let Some(Reverse((_, cost, i))) = heap.pop() {
+        let n = nodes[i].clone();
+        if cost > s.reached[&n].0 {
+            // already popped with a lower cost
+            continue;
+        }
+        if g.is_goal(&n) {
+            s.goal = Some(n);
+            break;
+        }
+        for (m, c) in g.successors(&n) {
+            let cost = cost + c;
+            if s.reached.get(&m).is_some_and(|&(d, _)| d <= cost) {
+                continue;
+            }
+            s.reached.insert(m.clone(), (cost, Some(n.clone())));
+            heap.push(Reverse((cost + heuristic(&m), cost, nodes.len())));
+            nodes.push(m);
+        }
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Numbers, going to `n + 1` costs 1 and `n * 2` costs 3.
+    struct Numbers(usize);
+
+    impl Graph for Numbers {
+        type Node = usize;
+
+        fn successors(&self, &n: &usize) -> Vec<(usize, usize)> {
+            [(n + 1, 1), (n * 2, 3)]
+                .into_iter()
+                .filter(|&(m, _)| m <= self.0)
+                .collect()
+        }
+        fn is_goal(&self, &n: &usize) -> bool {
+            n == self.0
+        }
+        fn heuristic(&self, &n: &usize) -> usize {
+            (n < self.0) as usize
+        }
+    }
+
+    #[test]
+    fn searches() {
+        let s = bfs(&Numbers(20), 1);
+        assert_eq!(s.goal_distance(), Some(5));
+        assert_eq!(s.goal_path().unwrap().len(), 6);
+
+        for s in [dijkstra(&Numbers(20), 1), astar(&Numbers(20), 1)] {
+            assert_eq!(s.goal_distance(), Some(10));
+            assert_eq!(s.goal_path(), Some(vec![1, 2, 3, 4, 5, 10, 20]));
+        }
+
+        let s = bfs(&Numbers(20), 30);
+        assert_eq!((s.goal, s.reached().count()), (None, 1));
+    }
+}
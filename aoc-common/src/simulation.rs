@@ -0,0 +1,33 @@
+/// A puzzle playing out one step at a time, which can be paused, inspected and resumed.
+pub trait Simulation {
+    /// What can be looked at between two steps.
+    type State: ?Sized;
+    /// Enough to come back to the current point with [`Simulation::restore`].
+    type Snapshot;
+
+    /// Advances by one step, returns `false` (without changing anything visible) once the
+    /// simulation is over.
+    fn step(&mut self) -> bool;
+    fn state(&self) -> &Self::State;
+    fn snapshot(&self) -> Self::Snapshot;
+    fn restore(&mut self, snapshot: Self::Snapshot);
+
+    /// Steps until `pred` holds for the state or the simulation is over, returns the number of
+    /// steps done.
+    fn run_until(&mut self, mut pred: impl FnMut(&Self::State) -> bool) -> usize {
+        let mut steps = 0;
+        while !pred(self.state()) && self.step() {
+            steps += 1;
+        }
+        steps
+    }
+
+    /// Does at most `n` steps, returns the number of steps done.
+    fn run(&mut self, n: usize) -> usize {
+        let mut steps = 0;
+        while steps < n && self.step() {
+            steps += 1;
+        }
+        steps
+    }
+}
@@ -0,0 +1,164 @@
+use std::{any::Any, borrow::Cow, io::BufRead, marker::PhantomData};
+
+use crate::{
+    answer::Answer,
+    dump::Dump,
+    normalise::{normalise, Trim},
+    params::Params,
+    parse::ParseError,
+    stream::Streamed,
+};
+
+/// A day of the calendar: how to parse its input and solve both parts.
+pub trait Solution {
+    const DAY: u8;
+    type Input: Dump + 'static;
+    type Output: Into<Answer>;
+    /// `()` for the days without parameters.
+    type Params: Params + Default;
+    /// What is trimmed from the inputs before they are parsed.
+    const TRIM: Trim = Trim::Lines;
+
+    /// Gets inputs normalised by [`normalise`] from the [`Solver`].
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input, params: &Self::Params) -> Self::Output;
+    fn part2(input: &Self::Input, params: &Self::Params) -> Self::Output;
+
+    /// Both parts solved in a single pass over `input`, without holding all of it in memory,
+    /// for the days which can (`None` for the others, which then read nothing).
+    fn stream(_input: &mut dyn BufRead, _params: &Self::Params) -> Streamed<Self::Output> {
+        None
+    }
+
+    /// Other implementations of the parts, to check against `part1` and `part2` and compare
+    /// their timings with.
+    fn variants() -> Vec<Variant<Self>>
+    where
+        Self: Sized,
+    {
+        Vec::new()
+    }
+}
+
+/// A named implementation of a part besides the main one, like the ones cargo-aoc runs for
+/// `#[aoc(dayN, partK, name)]`.
+pub struct Variant<S: Solution> {
+    pub part: u8,
+    pub name: &'static str,
+    pub solve: fn(&S::Input, &S::Params) -> S::Output,
+}
+
+impl<S: Solution> Variant<S> {
+    pub fn new(
+        part: u8,
+        name: &'static str,
+        solve: fn(&S::Input, &S::Params) -> S::Output,
+    ) -> Self {
+        Variant { part, name, solve }
+    }
+}
+
+/// Type-erased version of a [`Solution`], so days can be stored and iterated over together.
+pub trait Solver: Send + Sync {
+    fn day(&self) -> u8;
+    /// The default parameters of the day.
+    fn params(&self) -> Box<dyn Params>;
+    /// The input as the generator of the day expects it.
+    fn normalise<'a>(&self, input: &'a str) -> Cow<'a, str>;
+    /// Parses `input` once normalised.
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError>;
+    /// The parsed `input` as JSON. Panics if `input` does not come from this solver.
+    #[cfg(feature = "serde")]
+    fn to_json(&self, input: &dyn Any) -> serde_json::Result<serde_json::Value>;
+    /// Panics if `input` or `params` do not come from this solver.
+    fn part1(&self, input: &dyn Any, params: &dyn Params) -> Answer;
+    /// Panics if `input` or `params` do not come from this solver.
+    fn part2(&self, input: &dyn Any, params: &dyn Params) -> Answer;
+    /// See [`Solution::stream`]. Panics if `params` do not come from this solver.
+    fn stream(&self, input: &mut dyn BufRead, params: &dyn Params) -> Streamed<Answer>;
+    /// The names of the [`Variant`]s of `part`.
+    fn variants(&self, part: u8) -> Vec<&'static str>;
+    /// Solves `part` with the variant called `name`, `None` if there is no such variant.
+    /// Panics if `input` or `params` do not come from this solver.
+    fn variant(&self, part: u8, name: &str, input: &dyn Any, params: &dyn Params)
+        -> Option<Answer>;
+
+    /// Solves both parts with the default parameters.
+    fn solve(&self, input: &str) -> Result<(Answer, Answer), ParseError> {
+        let input = self.parse(input)?;
+        let params = self.params();
+        Ok((
+            self.part1(input.as_ref(), params.as_ref()),
+            self.part2(input.as_ref(), params.as_ref()),
+        ))
+    }
+}
+
+struct Erased<S>(PhantomData<fn() -> S>);
+
+impl<S: Solution> Erased<S> {
+    fn input(input: &dyn Any) -> &S::Input {
+        input
+            .downcast_ref()
+            .unwrap_or_else(|| panic!("input was not parsed by day {}", S::DAY))
+    }
+
+    fn params(params: &dyn Params) -> &S::Params {
+        params
+            .as_any()
+            .downcast_ref()
+            .unwrap_or_else(|| panic!("params are not the ones of day {}", S::DAY))
+    }
+}
+
+impl<S: Solution> Solver for Erased<S> {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+    fn params(&self) -> Box<dyn Params> {
+        Box::<S::Params>::default()
+    }
+    fn normalise<'a>(&self, input: &'a str) -> Cow<'a, str> {
+        normalise(input, S::TRIM)
+    }
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        Ok(Box::new(S::parse(&self.normalise(input))?))
+    }
+    #[cfg(feature = "serde")]
+    fn to_json(&self, input: &dyn Any) -> serde_json::Result<serde_json::Value> {
+        serde_json::to_value(Self::input(input))
+    }
+    fn part1(&self, input: &dyn Any, params: &dyn Params) -> Answer {
+        S::part1(Self::input(input), Self::params(params)).into()
+    }
+    fn part2(&self, input: &dyn Any, params: &dyn Params) -> Answer {
+        S::part2(Self::input(input), Self::params(params)).into()
+    }
+    fn stream(&self, input: &mut dyn BufRead, params: &dyn Params) -> Streamed<Answer> {
+        S::stream(input, Self::params(params))
+            .map(|answers| answers.map(|(part1, part2)| (part1.into(), part2.into())))
+    }
+    fn variants(&self, part: u8) -> Vec<&'static str> {
+        S::variants()
+            .into_iter()
+            .filter(|v| v.part == part)
+            .map(|v| v.name)
+            .collect()
+    }
+    fn variant(
+        &self,
+        part: u8,
+        name: &str,
+        input: &dyn Any,
+        params: &dyn Params,
+    ) -> Option<Answer> {
+        let v = S::variants()
+            .into_iter()
+            .find(|v| v.part == part && v.name == name)?;
+        Some((v.solve)(Self::input(input), Self::params(params)).into())
+    }
+}
+
+pub fn solver<S: Solution + 'static>() -> Box<dyn Solver> {
+    Box::new(Erased::<S>(PhantomData))
+}
@@ -0,0 +1,136 @@
+//! Inputs read as they come, for the days which never need all of them at once.
+
+use std::{
+    error::Error,
+    fmt,
+    io::{self, BufRead},
+};
+
+use crate::parse::{ParseError, Parser};
+
+/// Both answers of a day solved from a stream, or `None` if the day needs all of its input.
+pub type Streamed<T> = Option<Result<(T, T), StreamError>>;
+
+/// Why a streamed input could not be solved.
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StreamError::Io(e) => write!(f, "reading the input: {}", e),
+            StreamError::Parse(e) => e.fmt(f),
+        }
+    }
+}
+
+impl Error for StreamError {}
+
+impl From<io::Error> for StreamError {
+    fn from(e: io::Error) -> Self {
+        StreamError::Io(e)
+    }
+}
+
+impl From<ParseError> for StreamError {
+    fn from(e: ParseError) -> Self {
+        StreamError::Parse(e)
+    }
+}
+
+/// Skips the byte order mark at the start of `input`, if there is one.
+pub fn skip_bom(input: &mut impl BufRead) -> io::Result<()> {
+    if input.fill_buf()?.starts_with("\u{feff}".as_bytes()) {
+        input.consume(3);
+    }
+    Ok(())
+}
+
+/// The lines of an input, read one at a time into the same buffer, so only the longest line is
+/// ever in memory.
+///
+/// They are normalised like [`Trim::Lines`](crate::normalise::Trim::Lines) does for the inputs read whole: without byte order
+/// mark, trailing whitespace or blank lines at the end.
+pub struct Lines<R> {
+    day: u8,
+    reader: R,
+    buf: String,
+    /// 1-based number of the last line returned.
+    line: usize,
+    /// Blank lines read before the one in `buf`, still to return.
+    blank: usize,
+    /// Whether `buf` holds a line which was not returned yet.
+    held: bool,
+}
+
+impl<R: BufRead> Lines<R> {
+    pub fn new(day: u8, reader: R) -> Self {
+        Lines {
+            day,
+            reader,
+            buf: String::new(),
+            line: 0,
+            blank: 0,
+            held: false,
+        }
+    }
+
+    /// Reads the next line into `buf`, `false` at the end of the input.
+    fn read(&mut self) -> io::Result<bool> {
+        let first = self.line == 0 && self.blank == 0;
+        self.buf.clear();
+        let read = self.reader.read_line(&mut self.buf)? > 0;
+        if first && self.buf.starts_with('\u{feff}') {
+            self.buf.remove(0);
+        }
+        Ok(read)
+    }
+
+    /// The next line without its trailing whitespace, with a parser for the errors inside it, or
+    /// `None` at the end of the input.
+    pub fn next_line(&mut self) -> Result<Option<(Parser<'_>, &str)>, StreamError> {
+        if !self.held {
+            if !self.read()? {
+                return Ok(None);
+            }
+            // a blank line is only returned once there is something after it
+            while self.buf.trim_end().is_empty() {
+                self.blank += 1;
+                if !self.read()? {
+                    return Ok(None);
+                }
+            }
+            self.held = true;
+        }
+        self.line += 1;
+        if self.blank > 0 {
+            self.blank -= 1;
+            return Ok(Some((Parser::at_line(self.day, "", self.line), "")));
+        }
+        self.held = false;
+        let line = self.buf.trim_end();
+        Ok(Some((Parser::at_line(self.day, line, self.line), line)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lines() {
+        let mut lines = Lines::new(1, "\u{feff}12 \r\n\r\nx3\r\n\r\n".as_bytes());
+        let mut read = vec![];
+        while let Some((p, l)) = lines.next_line().unwrap() {
+            read.push(l.to_owned());
+            if let Some(i) = l.find('x') {
+                let e = p.error(&l[i + 1..], "y");
+                assert_eq!((e.line, e.column), (3, 2));
+            }
+        }
+        assert_eq!(read, ["12", "", "x3"]);
+    }
+}
@@ -0,0 +1,194 @@
+use std::{
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    path::PathBuf,
+    thread,
+    time::Duration,
+};
+
+use crate::{grid::Grid, simulation::Simulation};
+
+/// Something which can be looked at as a frame of characters.
+pub trait Draw {
+    fn draw(&self) -> Grid<char>;
+}
+
+/// Where frames go, one after the other.
+pub trait FrameSink {
+    fn frame(&mut self, frame: &Grid<char>) -> io::Result<()>;
+}
+
+/// Keeps the frames in memory.
+impl FrameSink for Vec<Grid<char>> {
+    fn frame(&mut self, frame: &Grid<char>) -> io::Result<()> {
+        self.push(frame.clone());
+        Ok(())
+    }
+}
+
+/// Plays the frames in an ANSI terminal, each one replacing the previous one.
+pub struct Terminal<W> {
+    out: W,
+    delay: Duration,
+}
+
+impl<W: Write> Terminal<W> {
+    pub fn new(out: W, delay: Duration) -> Self {
+        Terminal { out, delay }
+    }
+}
+
+impl<W: Write> FrameSink for Terminal<W> {
+    fn frame(&mut self, frame: &Grid<char>) -> io::Result<()> {
+        // clear the screen and go back to the top left corner
+        writeln!(self.out, "\x1b[2J\x1b[H{}", frame)?;
+        self.out.flush()?;
+        thread::sleep(self.delay);
+        Ok(())
+    }
+}
+
+/// Frames as plain text, separated by an empty line.
+pub struct Text<W> {
+    out: W,
+    first: bool,
+}
+
+impl<W: Write> Text<W> {
+    pub fn new(out: W) -> Self {
+        Text { out, first: true }
+    }
+}
+
+impl<W: Write> FrameSink for Text<W> {
+    fn frame(&mut self, frame: &Grid<char>) -> io::Result<()> {
+        if !self.first {
+            writeln!(self.out)?;
+        }
+        self.first = false;
+        writeln!(self.out, "{}", frame)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    /// Colour, with the [`colour`] of each character.
+    Ppm,
+    /// Grey levels, from the luminance of the colours.
+    Pgm,
+}
+
+/// One binary image per frame in a directory: `frame-00000.ppm`, `frame-00001.ppm`...
+pub struct Images {
+    dir: PathBuf,
+    format: ImageFormat,
+    /// Side in pixels of the square drawn for a character.
+    scale: usize,
+    count: usize,
+}
+
+impl Images {
+    /// Creates the directory if needed.
+    pub fn new(dir: impl Into<PathBuf>, format: ImageFormat, scale: usize) -> io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(Images {
+            dir,
+            format,
+            scale: scale.max(1),
+            count: 0,
+        })
+    }
+}
+
+impl FrameSink for Images {
+    fn frame(&mut self, frame: &Grid<char>) -> io::Result<()> {
+        let (magic, ext) = match self.format {
+            ImageFormat::Ppm => ("P6", "ppm"),
+            ImageFormat::Pgm => ("P5", "pgm"),
+        };
+        let path = self.dir.join(format!("frame-{:05}.{}", self.count, ext));
+        let mut out = BufWriter::new(File::create(path)?);
+        let (w, h) = (frame.width() * self.scale, frame.height() * self.scale);
+        write!(out, "{}\n{} {}\n255\n", magic, w, h)?;
+        for y in 0..h {
+            for c in frame.row(y / self.scale) {
+                let [r, g, b] = colour(*c);
+                let pixel = match self.format {
+                    ImageFormat::Ppm => vec![r, g, b],
+                    ImageFormat::Pgm => vec![luminance([r, g, b])],
+                };
+                for _ in 0..self.scale {
+                    out.write_all(&pixel)?;
+                }
+            }
+        }
+        out.flush()?;
+        self.count += 1;
+        Ok(())
+    }
+}
+
+/// Colour of a character in the images.
+pub fn colour(c: char) -> [u8; 3] {
+    match c {
+        ' ' => [0, 0, 0],
+        '.' => [32, 32, 40],
+        '#' => [200, 200, 200],
+        '|' | '-' => [120, 120, 120],
+        'o' => [230, 190, 90],
+        '+' | 'H' => [230, 40, 40],
+        '1'..='9' | 'T' => [240, 120, 60],
+        '>' | 'v' | '<' | '^' => [90, 160, 250],
+        _ => [255, 255, 255],
+    }
+}
+
+fn luminance([r, g, b]: [u8; 3]) -> u8 {
+    (0.299 * r as f64 + 0.587 * g as f64 + 0.114 * b as f64).round() as u8
+}
+
+/// Sends the frame of the start, then one after each step, for at most `max_steps` steps.
+/// Returns the number of steps done.
+pub fn record<S>(sim: &mut S, sink: &mut dyn FrameSink, max_steps: usize) -> io::Result<usize>
+where
+    S: Simulation + Draw,
+{
+    sink.frame(&sim.draw())?;
+    let mut steps = 0;
+    while steps < max_steps && sim.step() {
+        sink.frame(&sim.draw())?;
+        steps += 1;
+    }
+    Ok(steps)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text() {
+        let frames = [Grid::new(2, 1, '.'), Grid::new(2, 1, '#')];
+        let mut text = Text::new(Vec::new());
+        text.frame(&frames[0]).unwrap();
+        text.frame(&frames[1]).unwrap();
+        let text = String::from_utf8(text.out).unwrap();
+        assert_eq!(text, "..\n\n##\n");
+    }
+
+    #[test]
+    fn images() {
+        let dir = std::env::temp_dir().join(format!("aoc2022-viz-{}", std::process::id()));
+        let mut images = Images::new(&dir, ImageFormat::Pgm, 2).unwrap();
+        let mut frame = Grid::new(2, 1, '.');
+        frame[(1, 0)] = '#';
+        images.frame(&frame).unwrap();
+        images.frame(&frame).unwrap();
+        let pgm = fs::read(dir.join("frame-00001.pgm")).unwrap();
+        let (dark, light) = (luminance(colour('.')), luminance(colour('#')));
+        assert!(pgm.starts_with(b"P5\n4 2\n255\n"));
+        assert!(pgm.ends_with(&[dark, dark, light, light, dark, dark, light, light]));
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,123 @@
+//! Answers already found, so that the slow days only run again when their input, their
+//! parameters or the build of their solvers change.
+//!
+//! An answer is a JSON file named after its day, its part and the hashes of the rest of its key,
+//! in a directory per year.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+use aoc_common::{answer::Answer, params::Params};
+
+/// FNV-1a, which unlike the hasher of the standard library gives the same hashes whatever the
+/// platform or the version of Rust.
+fn hash(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |h, &b| {
+        (h ^ b as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// What the answers of a day depend on.
+pub struct Key {
+    year: u16,
+    day: u8,
+    input: u64,
+    /// Of the build and the parameters.
+    config: u64,
+}
+
+impl Key {
+    /// `input` is hashed as given, better normalised so that line endings do not matter. `build`
+    /// is the one of the crate of the year, like `aoc2022::BUILD`.
+    pub fn new(year: u16, day: u8, build: &str, input: &str, params: &dyn Params) -> Self {
+        let mut config = format!("build = {}\n", build);
+        for (name, value) in params.values() {
+            config.push_str(&format!("{} = {}\n", name, value));
+        }
+        Key {
+            year,
+            day,
+            input: hash(input.as_bytes()),
+            config: hash(config.as_bytes()),
+        }
+    }
+}
+
+/// Where an answer comes from.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Origin {
+    Computed,
+    Cached,
+    /// Computed while the cache had another answer, so either the day does not always give the
+    /// same answer or a dependency of its solver changed.
+    Differs(Answer),
+}
+
+#[derive(Debug, Clone)]
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Cache { dir: dir.into() }
+    }
+
+    fn path(&self, key: &Key, part: u8) -> PathBuf {
+        self.dir.join(key.year.to_string()).join(format!(
+            "day{}-part{}-{:016x}-{:016x}.json",
+            key.day, part, key.input, key.config
+        ))
+    }
+
+    /// The cached answer, if any. An unreadable one is as good as none, it is written again
+    /// once computed.
+    pub fn get(&self, key: &Key, part: u8) -> Option<Answer> {
+        let json = fs::read_to_string(self.path(key, part)).ok()?;
+        Answer::from_json(&serde_json::from_str(&json).ok()?)
+    }
+
+    pub fn put(&self, key: &Key, part: u8, answer: &Answer) -> Result<()> {
+        let path = self.path(key, part);
+        let write = |path: &Path| -> std::io::Result<()> {
+            fs::create_dir_all(path.parent().unwrap())?;
+            fs::write(path, answer.to_json().to_string())
+        };
+        write(&path).with_context(|| format!("writing {:?}", path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keys() {
+        let dir = std::env::temp_dir().join(format!("aoc-cache-{}", std::process::id()));
+        let cache = Cache::new(&dir);
+        let key = Key::new(2022, 1, "0.1.0+1", "1\n2", &());
+        assert_eq!(cache.get(&key, 1), None);
+        cache.put(&key, 1, &Answer::Int(3)).unwrap();
+        assert_eq!(cache.get(&key, 1), Some(Answer::Int(3)));
+        assert_eq!(cache.get(&key, 2), None);
+        assert_eq!(
+            cache.get(&Key::new(2022, 1, "0.1.0+2", "1\n2", &()), 1),
+            None
+        );
+        assert_eq!(
+            cache.get(&Key::new(2022, 1, "0.1.0+1", "1\n3", &()), 1),
+            None
+        );
+        let mut params = aoc2022::day10::Params::default();
+        let default = Key::new(2022, 10, "0.1.0+1", "noop", &params);
+        params.set("cycles", "1").unwrap();
+        assert_ne!(
+            Key::new(2022, 10, "0.1.0+1", "noop", &params).config,
+            default.config
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,124 @@
+//! Tasks run on their own threads, a few at a time, where a panic or a task taking too long only
+//! fails that task.
+
+use std::{
+    any::Any,
+    collections::HashMap,
+    panic::{self, AssertUnwindSafe},
+    sync::mpsc::{self, RecvTimeoutError},
+    thread,
+    time::{Duration, Instant},
+};
+
+/// A task, and the name of its thread.
+pub type Task<T> = (String, Box<dyn FnOnce() -> T + Send>);
+
+#[derive(Debug)]
+pub enum Outcome<T> {
+    Done(T),
+    Panicked(String),
+    /// Over its time budget. Its thread is left to finish on its own, there is no way to stop
+    /// it, and what it returns is ignored.
+    TimedOut,
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(s) => *s,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(s) => s.to_string(),
+            Err(_) => "panicked".to_owned(),
+        },
+    }
+}
+
+/// Runs at most `jobs` tasks at the same time, and returns their outcomes in the order of
+/// `tasks`.
+pub fn run<T: Send + 'static>(
+    tasks: Vec<Task<T>>,
+    jobs: usize,
+    budget: Option<Duration>,
+) -> Vec<Outcome<T>> {
+    let (tx, rx) = mpsc::channel();
+    let mut outcomes: Vec<Option<Outcome<T>>> = tasks.iter().map(|_| None).collect();
+    let mut pending = tasks.into_iter().enumerate();
+    let mut running: HashMap<usize, Instant> = HashMap::new();
+    loop {
+        while running.len() < jobs.max(1) {
+            let Some((i, (name, task))) = pending.next() else {
+                break;
+            };
+            let tx = tx.clone();
+            thread::Builder::new()
+                .name(name)
+                .spawn(move || {
+                    let res = panic::catch_unwind(AssertUnwindSafe(task));
+                    // nobody listens any more after a time out
+                    let _ = tx.send((i, res.map_err(panic_message)));
+                })
+                .expect("spawning a thread");
+            running.insert(i, Instant::now());
+        }
+        let Some(first) = running.values().min().copied() else {
+            break;
+        };
+        let received = match budget {
+            Some(budget) => {
+                rx.recv_timeout((first + budget).saturating_duration_since(Instant::now()))
+            }
+            None => rx.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+        match received {
+            Ok((i, res)) => {
+                // a late answer of a task which already timed out is not in `running` any more
+                if running.remove(&i).is_some() {
+                    outcomes[i] = Some(match res {
+                        Ok(v) => Outcome::Done(v),
+                        Err(msg) => Outcome::Panicked(msg),
+                    });
+                }
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                let budget = budget.unwrap();
+                running.retain(|&i, start| {
+                    let over = start.elapsed() >= budget;
+                    if over {
+                        outcomes[i] = Some(Outcome::TimedOut);
+                    }
+                    !over
+                });
+            }
+            Err(RecvTimeoutError::Disconnected) => unreachable!("`tx` is still there"),
+        }
+    }
+    outcomes.into_iter().map(Option::unwrap).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn isolated() {
+        // the slow task waits for the end of the run, so it only ends in time if the run does
+        // not wait for it
+        let (release, wait) = mpsc::channel::<()>();
+        let tasks: Vec<Task<()>> = vec![
+            ("fast".to_owned(), Box::new(|| ())),
+            ("panic".to_owned(), Box::new(|| panic!("no path"))),
+            (
+                "slow".to_owned(),
+                Box::new(move || {
+                    let _ = wait.recv_timeout(Duration::from_secs(60));
+                }),
+            ),
+            ("fast".to_owned(), Box::new(|| ())),
+        ];
+        let outcomes = run(tasks, 2, Some(Duration::from_secs(2)));
+        drop(release);
+        assert!(matches!(outcomes[0], Outcome::Done(())));
+        assert!(matches!(&outcomes[1], Outcome::Panicked(msg) if msg == "no path"));
+        assert!(matches!(outcomes[2], Outcome::TimedOut));
+        assert!(matches!(outcomes[3], Outcome::Done(())));
+    }
+}
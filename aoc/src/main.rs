@@ -0,0 +1,764 @@
+mod cache;
+mod jobs;
+mod scaffold;
+
+use std::{
+    any::Any,
+    collections::BTreeMap,
+    fs::{self, File},
+    io::{self, Read},
+    panic,
+    path::{Path, PathBuf},
+    sync::Arc,
+    thread,
+    time::Duration,
+};
+
+use anyhow::{Context, Result};
+use clap::{Parser, Subcommand, ValueEnum};
+
+use aoc2022::{day14, day17, day22, day23, day24, day9, gen, oracle};
+use aoc_common::{
+    answer::Answer,
+    params::Params,
+    profile::{self, DayProfile},
+    results::{self, DayResult},
+    solution::Solver,
+    viz::{self, FrameSink, ImageFormat, Images, Terminal, Text},
+};
+use cache::{Cache, Key, Origin};
+use jobs::{Outcome, Task};
+use scaffold::Template;
+
+/// Only with the `memory` feature, as counting slows down every allocation of every command.
+#[cfg(feature = "memory")]
+#[global_allocator]
+static ALLOC: aoc_common::profile::Counting = aoc_common::profile::Counting;
+
+#[derive(Parser)]
+#[command(name = "aoc2022", about = "Advent of Code solutions runner")]
+struct Cli {
+    #[command(subcommand)]
+    cmd: Cmd,
+}
+
+#[derive(Subcommand)]
+enum Cmd {
+    /// Run the solution of a day, or of all the days which have an input
+    ///
+    /// The answers are kept in cache/, by input, parameters and build of the solvers, and shown
+    /// as cached when they come from there.
+    Run {
+        /// Defaults to the latest year, or to all the years with an input directory with `--all`
+        #[arg(long)]
+        year: Option<u16>,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25), required_unless_present = "all")]
+        day: Option<u8>,
+        /// Run all the days which have an input, each on its own thread, where a panic only
+        /// fails that day
+        #[arg(long, conflicts_with_all = ["day", "input", "params"])]
+        all: bool,
+        /// Days run at the same time with `--all` (defaults to the number of CPUs)
+        #[arg(long, requires = "all")]
+        jobs: Option<usize>,
+        /// Seconds after which a day is reported as timed out with `--all`
+        #[arg(long, requires = "all")]
+        timeout: Option<f64>,
+        /// Only run this part (both parts are run by default)
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Input file, `-` to read stdin (defaults to input/YEAR/dayN.txt). The days which can are
+        /// solved as stdin is read, without keeping all of it in memory
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// TOML file overriding puzzle parameters, in a `[dayN]` table per day
+        #[arg(long)]
+        config: Option<PathBuf>,
+        /// Override a puzzle parameter, after the config file (e.g. `--param row=10`)
+        #[arg(long = "param", short, value_parser = parse_param)]
+        params: Vec<(String, String)>,
+        /// Compute the answers even if they are in cache/, and flag the ones which differ from
+        /// it (the inputs streamed from stdin are never cached)
+        #[arg(long)]
+        no_cache: bool,
+        /// Also run the other implementations of the parts, compare their timings with the main
+        /// ones and fail if their answers differ (never cached)
+        #[arg(long, conflicts_with = "all")]
+        variants: bool,
+    },
+    /// Print the parsed input of a day, to see what the parser made of it or to compare two
+    /// versions of the parser
+    Parse {
+        /// Defaults to the latest year
+        #[arg(long)]
+        year: Option<u16>,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Input file, `-` to read stdin (defaults to input/YEAR/dayN.txt)
+        #[arg(long)]
+        input: Option<PathBuf>,
+        #[arg(long, value_enum, default_value_t = Format::Json)]
+        format: Format,
+    },
+    /// Run every day which has an input in input/YEAR, and report answers and timings
+    ///
+    /// The Markdown table is printed when no output file is given.
+    Results {
+        /// Only this year, instead of all the years with an input directory
+        #[arg(long)]
+        year: Option<u16>,
+        /// Write the results as JSON to this file
+        #[arg(long)]
+        json: Option<PathBuf>,
+        /// Write the results as a Markdown table to this file
+        #[arg(long)]
+        markdown: Option<PathBuf>,
+        /// TOML file overriding puzzle parameters, in a `[dayN]` table per day
+        #[arg(long)]
+        config: Option<PathBuf>,
+    },
+    /// Run every day which has an input in input/YEAR, and report the time and memory of each
+    /// phase
+    ///
+    /// The peak is the most memory in use at once since the start of the day, and the
+    /// allocated memory counts everything allocated, even if freed right after. Only built with
+    /// `--features memory`, which slows down the allocations of every command.
+    Profile {
+        /// Only this year, instead of all the years with an input directory
+        #[arg(long)]
+        year: Option<u16>,
+        /// Only this day
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        /// TOML file overriding puzzle parameters, in a `[dayN]` table per day
+        #[arg(long)]
+        config: Option<PathBuf>,
+    },
+    /// Print a random 2022 input, the same for the same seed and size
+    Gen {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// From 0 (tiny) up, 1 is about the size of the real inputs
+        #[arg(long, default_value_t = 1)]
+        size: usize,
+    },
+    /// Compare the solutions to slow but simple oracles on generated inputs, stop at the first
+    /// disagreement (2022 days 16, 17, 21 and 22)
+    Check {
+        /// Only check this day
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        /// Number of seeds, from 0
+        #[arg(long, default_value_t = 100)]
+        seeds: u64,
+        #[arg(long, default_value_t = 0)]
+        size: usize,
+    },
+    /// Create the module of a new day from a template, with an empty example
+    New {
+        /// Year of the crate to add the day to, `aocYEAR`
+        #[arg(long)]
+        year: u16,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        #[arg(long, value_enum, default_value_t = Template::Lines)]
+        template: Template,
+    },
+    /// Show a simulation step by step (2022 days 9, 14, 17, 22, 23 and 24)
+    Viz {
+        /// Defaults to the latest year
+        #[arg(long)]
+        year: Option<u16>,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Simulate as in this part, for the days where they differ
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Input file, `-` to read stdin (defaults to input/YEAR/dayN.txt)
+        #[arg(long)]
+        input: Option<PathBuf>,
+        #[arg(long, value_enum, default_value_t = Backend::Terminal)]
+        backend: Backend,
+        /// Directory of the images, or file of the text frames (stdout by default)
+        #[arg(long)]
+        out: Option<PathBuf>,
+        /// Stop after this many steps
+        #[arg(long, default_value_t = 1000)]
+        steps: usize,
+        /// Milliseconds between two frames in the terminal
+        #[arg(long, default_value_t = 50)]
+        delay: u64,
+        /// Pixels per character in the images
+        #[arg(long, default_value_t = 4)]
+        scale: usize,
+    },
+}
+
+/// How `parse` prints the inputs.
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// Pretty-printed, one value per line so that dumps can be diffed
+    Json,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Backend {
+    Terminal,
+    Text,
+    Ppm,
+    Pgm,
+}
+
+fn parse_param(s: &str) -> Result<(String, String), String> {
+    s.split_once('=')
+        .map(|(name, value)| (name.trim().to_owned(), value.trim().to_owned()))
+        .ok_or_else(|| format!("expected `NAME=VALUE`, got {:?}", s))
+}
+
+/// A TOML value as the text a parameter is parsed from.
+fn text(v: &toml::Value) -> String {
+    match v {
+        toml::Value::String(s) => s.clone(),
+        toml::Value::Array(a) => a.iter().map(text).collect::<Vec<_>>().join(","),
+        v => v.to_string(),
+    }
+}
+
+fn read_params(
+    solver: &dyn Solver,
+    config: Option<PathBuf>,
+    overrides: &[(String, String)],
+) -> Result<Box<dyn Params>> {
+    let mut params = solver.params();
+    if let Some(path) = config {
+        let config: toml::Table = fs::read_to_string(&path)
+            .with_context(|| format!("reading {:?}", path))?
+            .parse()
+            .with_context(|| format!("parsing {:?}", path))?;
+        let table = format!("day{}", solver.day());
+        if let Some(day) = config.get(&table) {
+            let day = day
+                .as_table()
+                .with_context(|| format!("`{}` is not a table in {:?}", table, path))?;
+            for (name, value) in day {
+                params
+                    .set(name, &text(value))
+                    .with_context(|| format!("in {:?}", path))?;
+            }
+        }
+    }
+    for (name, value) in overrides {
+        params.set(name, value)?;
+    }
+    Ok(params)
+}
+
+type Registry = BTreeMap<u8, Box<dyn Solver>>;
+
+/// The years of the crates the runner is built with, and their days.
+fn years() -> BTreeMap<u16, Registry> {
+    BTreeMap::from([(aoc2022::YEAR, aoc2022::solution::registry())])
+}
+
+/// The build of the crate of `year`, which its cached answers depend on.
+fn build(year: u16) -> &'static str {
+    match year {
+        aoc2022::YEAR => aoc2022::BUILD,
+        _ => "",
+    }
+}
+
+/// The days of `year`, or of the latest year.
+fn year(year: Option<u16>) -> Result<(u16, Registry)> {
+    let mut years = years();
+    let known = years
+        .keys()
+        .map(u16::to_string)
+        .collect::<Vec<_>>()
+        .join(", ");
+    let year = match year {
+        Some(year) => year,
+        None => *years.keys().last().context("no years")?,
+    };
+    let days = years
+        .remove(&year)
+        .with_context(|| format!("no solutions for {}, only for {}", year, known))?;
+    Ok((year, days))
+}
+
+/// Answers already found, by input and parameters.
+fn cache() -> Cache {
+    Cache::new("cache")
+}
+
+fn input_path(year: u16, day: u8) -> PathBuf {
+    format!("input/{}/day{}.txt", year, day).into()
+}
+
+fn parts(part: Option<u8>) -> Vec<u8> {
+    part.map_or(vec![1, 2], |p| vec![p])
+}
+
+fn solve(solver: &dyn Solver, part: u8, input: &dyn Any, params: &dyn Params) -> Answer {
+    match part {
+        1 => solver.part1(input, params),
+        2 => solver.part2(input, params),
+        _ => unreachable!(),
+    }
+}
+
+fn print_answer(day: u8, part: u8, answer: &Answer, origin: &Origin) {
+    let mark = match origin {
+        Origin::Computed => String::new(),
+        Origin::Cached => " (cached)".to_owned(),
+        Origin::Differs(Answer::Image(_)) => " (differs from the cached image)".to_owned(),
+        Origin::Differs(cached) => format!(" (differs from the cached {})", cached),
+    };
+    match answer {
+        Answer::Image(_) => println!("Day {} - Part {}{}:\n{}", day, part, mark, answer),
+        _ => println!("Day {} - Part {}: {}{}", day, part, answer, mark),
+    }
+}
+
+/// The answers of `parts`, from `cache` if it has them all and they are not to be recomputed,
+/// with where each one comes from. The answers computed are cached.
+fn answers(
+    year: u16,
+    solver: &dyn Solver,
+    input: &str,
+    params: &dyn Params,
+    parts: &[u8],
+    cache: &Cache,
+    recompute: bool,
+) -> Result<Vec<(Answer, Origin)>> {
+    let key = Key::new(
+        year,
+        solver.day(),
+        build(year),
+        &solver.normalise(input),
+        params,
+    );
+    let cached: Option<Vec<Answer>> = parts.iter().map(|&p| cache.get(&key, p)).collect();
+    if let Some(cached) = cached.filter(|_| !recompute) {
+        return Ok(cached.into_iter().map(|a| (a, Origin::Cached)).collect());
+    }
+    let input = solver.parse(input)?;
+    parts
+        .iter()
+        .map(|&part| {
+            let answer = solve(solver, part, input.as_ref(), params);
+            let origin = match cache.get(&key, part) {
+                Some(cached) if cached != answer => Origin::Differs(cached),
+                _ => Origin::Computed,
+            };
+            cache.put(&key, part, &answer)?;
+            Ok((answer, origin))
+        })
+        .collect()
+}
+
+/// Runs every implementation of `parts` and prints how long each took, failing if one of them
+/// disagrees with the main one.
+fn run_variants(solver: &dyn Solver, input: &str, params: &dyn Params, parts: &[u8]) -> Result<()> {
+    let day = solver.day();
+    let input = solver.parse(input)?;
+    let mut disagreeing = vec![];
+    for &part in parts {
+        let runs = results::run_variants(solver, input.as_ref(), params, part);
+        let (main, main_time) = (&runs[0].1, runs[0].2);
+        print_answer(day, part, main, &Origin::Computed);
+        for (name, answer, time) in &runs {
+            let speedup = main_time.as_secs_f64() / time.as_secs_f64();
+            print!("  {:<8} {:>10.2?} {:>8.2}x", name, time, speedup);
+            if answer == main {
+                println!();
+            } else {
+                println!("  but got {}", answer);
+                disagreeing.push(format!("part {} {}", part, name));
+            }
+        }
+    }
+    anyhow::ensure!(
+        disagreeing.is_empty(),
+        "day {}: {} disagreeing with the main implementation",
+        day,
+        disagreeing.join(", ")
+    );
+    Ok(())
+}
+
+/// Runs the days on `jobs` threads, then prints their answers in order, or why they have none.
+fn run_all(
+    days: Vec<DayInput>,
+    parts: Vec<u8>,
+    config: Option<PathBuf>,
+    jobs: usize,
+    budget: Option<Duration>,
+    recompute: bool,
+) -> Result<()> {
+    let names: Vec<(u16, u8)> = days.iter().map(|d| (d.year, d.solver.day())).collect();
+    let tasks: Vec<Task<_>> = days
+        .into_iter()
+        .map(|DayInput { year, solver, path }| {
+            let (parts, config) = (parts.clone(), config.clone());
+            let name = format!("{} day {}", year, solver.day());
+            let task = move || {
+                let params = read_params(solver.as_ref(), config, &[])?;
+                let input = read_input(year, solver.day(), Some(path))?;
+                let solver = solver.as_ref();
+                answers(
+                    year,
+                    solver,
+                    &input,
+                    params.as_ref(),
+                    &parts,
+                    &cache(),
+                    recompute,
+                )
+            };
+            (name, Box::new(task) as Box<dyn FnOnce() -> _ + Send>)
+        })
+        .collect();
+
+    // the panics are reported with the other failures, not as they happen
+    let hook = Arc::new(panic::take_hook());
+    let main_only = Arc::clone(&hook);
+    panic::set_hook(Box::new(move |info| {
+        if thread::current().name() == Some("main") {
+            main_only(info)
+        }
+    }));
+    let outcomes = jobs::run(tasks, jobs, budget);
+    drop(panic::take_hook());
+    panic::set_hook(Box::new(move |info| hook(info)));
+
+    let several_years = names.iter().any(|n| n.0 != names[0].0);
+    let (mut failed, mut last_year) = (0, None);
+    for ((year, day), outcome) in names.into_iter().zip(outcomes) {
+        if several_years && last_year != Some(year) {
+            println!("{}", year);
+            last_year = Some(year);
+        }
+        match outcome {
+            Outcome::Done(Ok(answers)) => {
+                for (&part, (answer, origin)) in parts.iter().zip(answers) {
+                    print_answer(day, part, &answer, &origin);
+                }
+                continue;
+            }
+            Outcome::Done(Err(e)) => println!("Day {} - failed: {:#}", day, e),
+            Outcome::Panicked(msg) => println!("Day {} - panicked: {}", day, msg),
+            Outcome::TimedOut => println!(
+                "Day {} - timed out after {:.2?}",
+                day,
+                budget.unwrap_or_default()
+            ),
+        }
+        failed += 1;
+    }
+    if failed > 0 {
+        anyhow::bail!("{} days without answers", failed);
+    }
+    Ok(())
+}
+
+/// A day and the path of its input.
+struct DayInput {
+    year: u16,
+    solver: Box<dyn Solver>,
+    path: PathBuf,
+}
+
+/// The days of `year`, or of all the years with an input directory, which have an input.
+fn days_with_input(year: Option<u16>, day: Option<u8>) -> Result<Vec<DayInput>> {
+    let years = match year {
+        Some(y) => BTreeMap::from([self::year(Some(y))?]),
+        None => years()
+            .into_iter()
+            .filter(|(y, _)| Path::new(&format!("input/{}", y)).is_dir())
+            .collect(),
+    };
+    let mut days = Vec::new();
+    for (y, registry) in years {
+        for (d, solver) in registry {
+            let path = input_path(y, d);
+            if day.is_none_or(|day| day == d) && path.exists() {
+                days.push(DayInput {
+                    year: y,
+                    solver,
+                    path,
+                });
+            }
+        }
+    }
+    Ok(days)
+}
+
+fn read_input(year: u16, day: u8, path: Option<PathBuf>) -> Result<String> {
+    let path = path.unwrap_or_else(|| input_path(year, day));
+    let mut input = String::new();
+    if path.as_os_str() == "-" {
+        io::stdin()
+            .read_to_string(&mut input)
+            .context("reading stdin")?;
+    } else {
+        input = fs::read_to_string(&path).with_context(|| format!("reading {:?}", path))?;
+    }
+    // normalised by the solver of the day
+    Ok(input)
+}
+
+fn sink(
+    backend: Backend,
+    out: Option<PathBuf>,
+    delay: u64,
+    scale: usize,
+) -> Result<Box<dyn FrameSink>> {
+    let image_dir = || out.clone().unwrap_or_else(|| "frames".into());
+    Ok(match backend {
+        Backend::Terminal => Box::new(Terminal::new(io::stdout(), Duration::from_millis(delay))),
+        Backend::Text => match &out {
+            Some(path) => Box::new(Text::new(io::BufWriter::new(
+                File::create(path).with_context(|| format!("creating {:?}", path))?,
+            ))),
+            None => Box::new(Text::new(io::stdout())),
+        },
+        Backend::Ppm => Box::new(Images::new(image_dir(), ImageFormat::Ppm, scale)?),
+        Backend::Pgm => Box::new(Images::new(image_dir(), ImageFormat::Pgm, scale)?),
+    })
+}
+
+/// Sends the frames of a day to `sink`, returns the number of steps done.
+fn record(
+    year: u16,
+    day: u8,
+    part: u8,
+    input: &str,
+    sink: &mut dyn FrameSink,
+    steps: usize,
+) -> Result<usize> {
+    anyhow::ensure!(
+        year == aoc2022::YEAR,
+        "{} has no visualisation, only {}",
+        year,
+        aoc2022::YEAR
+    );
+    let part2 = part == 2;
+    Ok(match day {
+        9 => {
+            let moves = day9::input_generator(input)?;
+            viz::record(
+                &mut day9::Rope::new(&moves, if part2 { 9 } else { 1 }),
+                sink,
+                steps,
+            )?
+        }
+        14 => {
+            let map = day14::input_generator(input)?;
+            viz::record(&mut day14::Sand::new(map, part2), sink, steps)?
+        }
+        17 => viz::record(&mut day17::input_generator(input)?, sink, steps)?,
+        22 => day22::input_generator(input)?.record(part2, sink, steps)?,
+        23 => {
+            let grid = day23::input_generator(input)?;
+            viz::record(&mut day23::Elves::new(grid), sink, steps)?
+        }
+        24 => viz::record(&mut day24::input_generator(input)?, sink, steps)?,
+        _ => anyhow::bail!("day {} has no visualisation", day),
+    })
+}
+
+fn main() -> Result<()> {
+    match Cli::parse().cmd {
+        Cmd::Run {
+            year: y,
+            day: None,
+            part,
+            config,
+            jobs,
+            timeout,
+            no_cache,
+            ..
+        } => {
+            let jobs =
+                jobs.unwrap_or_else(|| thread::available_parallelism().map_or(1, |n| n.get()));
+            run_all(
+                days_with_input(y, None)?,
+                parts(part),
+                config,
+                jobs,
+                timeout.map(Duration::from_secs_f64),
+                no_cache,
+            )?;
+        }
+        Cmd::Run {
+            year: y,
+            day: Some(day),
+            part,
+            input,
+            config,
+            params,
+            no_cache,
+            variants,
+            ..
+        } => {
+            let (y, registry) = year(y)?;
+            let solver = registry.get(&day).context("no solution for this day")?;
+            let params = read_params(solver.as_ref(), config, &params)?;
+            let stdin = input.as_deref() == Some(Path::new("-"));
+            if variants {
+                let input = read_input(y, day, input)?;
+                run_variants(solver.as_ref(), &input, params.as_ref(), &parts(part))?;
+            } else if let Some(answers) = stdin
+                .then(|| solver.stream(&mut io::stdin().lock(), params.as_ref()))
+                .flatten()
+            {
+                let answers = <[Answer; 2]>::from(answers?);
+                for part in parts(part) {
+                    print_answer(day, part, &answers[part as usize - 1], &Origin::Computed);
+                }
+            } else {
+                let input = read_input(y, day, input)?;
+                let parts = parts(part);
+                let solver = solver.as_ref();
+                let answers = answers(
+                    y,
+                    solver,
+                    &input,
+                    params.as_ref(),
+                    &parts,
+                    &cache(),
+                    no_cache,
+                )?;
+                for (part, (answer, origin)) in parts.into_iter().zip(answers) {
+                    print_answer(day, part, &answer, &origin);
+                }
+            }
+        }
+        Cmd::Parse {
+            year: y,
+            day,
+            input,
+            format,
+        } => {
+            let (y, registry) = year(y)?;
+            let solver = registry.get(&day).context("no solution for this day")?;
+            let input = solver.parse(&read_input(y, day, input)?)?;
+            let json = solver.to_json(input.as_ref())?;
+            match format {
+                Format::Json => println!("{}", serde_json::to_string_pretty(&json)?),
+            }
+        }
+        Cmd::Results {
+            year: y,
+            json,
+            markdown,
+            config,
+        } => {
+            let mut all: Vec<DayResult> = Vec::new();
+            for DayInput {
+                year: y,
+                solver,
+                path,
+            } in days_with_input(y, None)?
+            {
+                let params = read_params(solver.as_ref(), config.clone(), &[])?;
+                let input = read_input(y, solver.day(), Some(path))?;
+                all.push(results::run_day(
+                    y,
+                    solver.as_ref(),
+                    &input,
+                    params.as_ref(),
+                )?);
+            }
+            if let Some(path) = &json {
+                let json = serde_json::to_string_pretty(&results::to_json(&all))?;
+                fs::write(path, json + "\n").with_context(|| format!("writing {:?}", path))?;
+            }
+            if let Some(path) = &markdown {
+                fs::write(path, results::to_markdown(&all))
+                    .with_context(|| format!("writing {:?}", path))?;
+            }
+            if json.is_none() && markdown.is_none() {
+                print!("{}", results::to_markdown(&all));
+            }
+        }
+        Cmd::Profile {
+            year: y,
+            day,
+            config,
+        } => {
+            anyhow::ensure!(
+                cfg!(feature = "memory"),
+                "the memory is only counted when built with `--features memory`"
+            );
+            let mut all: Vec<DayProfile> = Vec::new();
+            for DayInput {
+                year: y,
+                solver,
+                path,
+            } in days_with_input(y, day)?
+            {
+                let params = read_params(solver.as_ref(), config.clone(), &[])?;
+                let input = read_input(y, solver.day(), Some(path))?;
+                all.push(profile::profile_day(
+                    y,
+                    solver.as_ref(),
+                    &input,
+                    params.as_ref(),
+                )?);
+            }
+            print!("{}", profile::to_markdown(&all));
+        }
+        Cmd::Gen { day, seed, size } => {
+            println!(
+                "{}",
+                gen::generate(day, seed, size).context("no generator for this day")?
+            );
+        }
+        Cmd::Check { day, seeds, size } => {
+            let days = match day {
+                Some(day) if oracle::DAYS.contains(&day) => vec![day],
+                Some(day) => anyhow::bail!("no oracle for day {}", day),
+                None => oracle::DAYS.to_vec(),
+            };
+            for day in days {
+                match oracle::check(day, 0..seeds, size) {
+                    Ok(compared) => println!("day {}: {} answers agree", day, compared),
+                    Err(disagreement) => anyhow::bail!("{}", disagreement),
+                }
+            }
+        }
+        Cmd::New {
+            year,
+            day,
+            template,
+        } => {
+            for path in scaffold::new_day(Path::new("."), year, day, template)? {
+                println!("wrote {}", path.display());
+            }
+        }
+        Cmd::Viz {
+            year: y,
+            day,
+            part,
+            input,
+            backend,
+            out,
+            steps,
+            delay,
+            scale,
+        } => {
+            let (y, mut registry) = year(y)?;
+            let input = read_input(y, day, input)?;
+            let solver = registry.remove(&day).context("no solution for this day")?;
+            let input = solver.normalise(&input);
+            let mut sink = sink(backend, out, delay, scale)?;
+            let steps = record(y, day, part, &input, sink.as_mut(), steps)?;
+            eprintln!("{} steps", steps);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,170 @@
+//! Files of a new day: its module from a template, declared in the `lib.rs` of its year and
+//! added to the registry, and an example to fill in.
+
+use std::{
+    fs,
+    io::{self, ErrorKind},
+    path::{Path, PathBuf},
+};
+
+/// How the input of the new day is parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Template {
+    /// A number per line
+    Lines,
+    /// A map of digits
+    Grid,
+    /// Blocks of numbers separated by empty lines
+    Blocks,
+    /// A record per line, matched by a regex
+    Records,
+}
+
+impl Template {
+    /// The module of `day`.
+    pub fn render(self, day: u8) -> String {
+        let template = match self {
+            Template::Lines => include_str!("../templates/lines.rs.in"),
+            Template::Grid => include_str!("../templates/grid.rs.in"),
+            Template::Blocks => include_str!("../templates/blocks.rs.in"),
+            Template::Records => include_str!("../templates/records.rs.in"),
+        };
+        template.replace("{{day}}", &day.to_string())
+    }
+}
+
+fn invalid(msg: String) -> io::Error {
+    io::Error::new(ErrorKind::InvalidData, msg)
+}
+
+/// Inserts `pub mod name;` among the module declarations of `lib`, in the order rustfmt keeps
+/// them in.
+pub fn declare_module(lib: &str, name: &str) -> Option<String> {
+    fn module(l: &str) -> Option<&str> {
+        l.strip_prefix("pub mod ")?.strip_suffix(';')
+    }
+    let mut lines: Vec<&str> = lib.lines().collect();
+    let mods: Vec<usize> = (0..lines.len())
+        .filter(|&i| module(lines[i]).is_some())
+        .collect();
+    let at = mods
+        .iter()
+        .find(|&&i| module(lines[i]) >= Some(name))
+        .copied()
+        .or_else(|| mods.last().map(|i| i + 1))?;
+    if module(lines.get(at)?) == Some(name) {
+        return None;
+    }
+    let decl = format!("pub mod {};", name);
+    lines.insert(at, &decl);
+    Some(lines.join("\n") + "\n")
+}
+
+/// Inserts the solver of `day` in the list of the `registry` of a year, which is in the order of
+/// the days.
+pub fn register(solution: &str, day: u8) -> Option<String> {
+    let day_of = |l: &str| {
+        l.trim()
+            .strip_prefix("solver::<day")?
+            .split_once("::")?
+            .0
+            .parse::<u8>()
+            .ok()
+    };
+    let mut lines: Vec<&str> = solution.lines().collect();
+    let solvers: Vec<usize> = (0..lines.len())
+        .filter(|&i| day_of(lines[i]).is_some())
+        .collect();
+    let at = solvers
+        .iter()
+        .find(|&&i| day_of(lines[i]) >= Some(day))
+        .copied()
+        .or_else(|| solvers.last().map(|i| i + 1))?;
+    if day_of(lines.get(at)?) == Some(day) {
+        return None;
+    }
+    let line = format!("        solver::<day{}::Day{}>(),", day, day);
+    lines.insert(at, &line);
+    Some(lines.join("\n") + "\n")
+}
+
+/// Creates the files of `day` in the crate of `year` in the workspace at `root`, and returns
+/// their paths. Nothing is overwritten: it fails if the day already has a module, and keeps an
+/// existing example.
+pub fn new_day(root: &Path, year: u16, day: u8, template: Template) -> io::Result<Vec<PathBuf>> {
+    let krate = root.join(format!("aoc{}", year));
+    if !krate.is_dir() {
+        return Err(io::Error::new(
+            ErrorKind::NotFound,
+            format!("no crate {:?} for {} in the workspace", krate, year),
+        ));
+    }
+    let module = krate.join(format!("src/day{}.rs", day));
+    if module.exists() {
+        return Err(io::Error::new(
+            ErrorKind::AlreadyExists,
+            format!("{:?} already exists", module),
+        ));
+    }
+    let lib_path = krate.join("src/lib.rs");
+    let lib = declare_module(&fs::read_to_string(&lib_path)?, &format!("day{}", day))
+        .ok_or_else(|| invalid(format!("cannot declare day {} in {:?}", day, lib_path)))?;
+    let solution_path = krate.join("src/solution.rs");
+    let solution = register(&fs::read_to_string(&solution_path)?, day).ok_or_else(|| {
+        invalid(format!(
+            "cannot register day {} in {:?}",
+            day, solution_path
+        ))
+    })?;
+
+    let examples = root.join(format!("examples/{}", year));
+    fs::create_dir_all(&examples)?;
+    let example = examples.join(format!("day{}-1.txt", day));
+    let answers = example.with_extension("toml");
+    fs::write(&module, template.render(day))?;
+    fs::write(&lib_path, lib)?;
+    fs::write(&solution_path, solution)?;
+    let mut written = vec![module, lib_path, solution_path];
+    if !example.exists() {
+        fs::write(&example, "")?;
+        fs::write(
+            &answers,
+            format!(
+                "# expected answers of day{}-1.txt, remove `ignore` once it is filled in\n\
+                 ignore = \"no example yet\"\n\
+                 part1 = 0\n\
+                 part2 = 0\n",
+                day
+            ),
+        )?;
+        written.extend([example, answers]);
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sorted() {
+        let lib = "pub mod day1;\npub mod day10;\npub mod day20;\npub mod day3;\npub mod grid;\naoc_lib! {}\n";
+        assert_eq!(
+            declare_module(lib, "day2").unwrap(),
+            "pub mod day1;\npub mod day10;\npub mod day2;\npub mod day20;\npub mod day3;\npub mod grid;\naoc_lib! {}\n"
+        );
+        assert_eq!(declare_module(lib, "day3"), None);
+
+        let solution =
+            "    [\n        solver::<day1::Day1>(),\n        solver::<day10::Day10>(),\n    ]\n";
+        assert_eq!(
+            register(solution, 2).unwrap(),
+            "    [\n        solver::<day1::Day1>(),\n        solver::<day2::Day2>(),\n        solver::<day10::Day10>(),\n    ]\n"
+        );
+        assert_eq!(
+            register(solution, 11).unwrap(),
+            "    [\n        solver::<day1::Day1>(),\n        solver::<day10::Day10>(),\n        solver::<day11::Day11>(),\n    ]\n"
+        );
+        assert_eq!(register(solution, 10), None);
+    }
+}
@@ -0,0 +1,91 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use criterion::{black_box, Criterion, SamplingMode};
+
+use aoc2022::solution::registry;
+use aoc_common::solution::Solver;
+
+const PHASES: [&str; 3] = ["parse", "part1", "part2"];
+
+fn root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn output_dir() -> PathBuf {
+    root().join("../target/criterion")
+}
+
+fn bench_day(c: &mut Criterion, solver: &dyn Solver, input: &str) {
+    let parsed = solver.parse(input).unwrap();
+    let params = solver.params();
+    let params = params.as_ref();
+
+    // the slowest days take seconds per run, don't spend minutes sampling them
+    let start = Instant::now();
+    solver.part1(parsed.as_ref(), params);
+    solver.part2(parsed.as_ref(), params);
+    let slow = start.elapsed() > Duration::from_millis(100);
+
+    let mut group = c.benchmark_group(format!("day{}", solver.day()));
+    if slow {
+        group.sample_size(10).sampling_mode(SamplingMode::Flat);
+    }
+    group.bench_function(PHASES[0], |b| b.iter(|| solver.parse(black_box(input))));
+    group.bench_function(PHASES[1], |b| {
+        b.iter(|| solver.part1(black_box(parsed.as_ref()), params))
+    });
+    group.bench_function(PHASES[2], |b| {
+        b.iter(|| solver.part2(black_box(parsed.as_ref()), params))
+    });
+    group.finish();
+}
+
+/// Mean time of a bench from the last run, as saved by criterion.
+fn mean(day: u8, phase: &str) -> Option<Duration> {
+    let path = output_dir().join(format!("day{}/{}/new/estimates.json", day, phase));
+    let estimates: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(path).ok()?).ok()?;
+    let ns = estimates["mean"]["point_estimate"].as_f64()?;
+    Some(Duration::from_nanos(ns as u64))
+}
+
+fn summary(days: &[u8]) {
+    println!("\n| day | parse | part1 | part2 | total |");
+    println!("|----:|------:|------:|------:|------:|");
+    for &day in days {
+        let times = PHASES.map(|phase| mean(day, phase));
+        if times.iter().all(Option::is_none) {
+            continue;
+        }
+        let total: Duration = times.iter().flatten().sum();
+        let [parse, part1, part2] =
+            times.map(|t| t.map_or("-".to_string(), |t| format!("{:.2?}", t)));
+        println!(
+            "| {} | {} | {} | {} | {:.2?} |",
+            day, parse, part1, part2, total
+        );
+    }
+}
+
+fn main() {
+    let mut c = Criterion::default()
+        .output_directory(&output_dir())
+        .configure_from_args();
+
+    let mut days = Vec::new();
+    for (day, solver) in registry() {
+        let Ok(input) = fs::read_to_string(root().join(format!("../input/2022/day{}.txt", day)))
+        else {
+            continue;
+        };
+        bench_day(&mut c, solver.as_ref(), &input);
+        days.push(day);
+    }
+
+    c.final_summary();
+    summary(&days);
+}
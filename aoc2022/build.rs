@@ -0,0 +1,76 @@
+//! Turns every example in `examples/YEAR/dayN-K.txt` into a test of `tests/examples.rs`, for the
+//! year in the name of the crate, and hashes the sources of the solvers for `BUILD`.
+
+use std::{env, fs, path::Path};
+
+/// The sources the answers depend on, those of this crate and of the common one.
+const SOURCES: [&str; 2] = ["src", "../aoc-common/src"];
+
+/// FNV-1a of the names and contents of the files in `SOURCES`, in order, like the runner hashes
+/// the rest of the keys of its cache.
+fn hash_sources() -> u64 {
+    let mut files: Vec<_> = SOURCES
+        .iter()
+        .flat_map(|dir| fs::read_dir(dir).unwrap())
+        .map(|file| file.unwrap().path())
+        .collect();
+    files.sort();
+    let mut bytes = Vec::new();
+    for path in files {
+        bytes.extend(path.to_str().unwrap().as_bytes());
+        bytes.extend(fs::read(&path).unwrap());
+    }
+    bytes.iter().fold(0xcbf29ce484222325, |h, &b| {
+        (h ^ b as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+fn main() {
+    for dir in SOURCES {
+        println!("cargo:rerun-if-changed={}", dir);
+    }
+    println!("cargo:rustc-env=AOC_SOURCES_HASH={:016x}", hash_sources());
+
+    let name = env::var("CARGO_PKG_NAME").unwrap();
+    let year = name.strip_prefix("aoc").unwrap();
+    let dir = Path::new("../examples").join(year);
+    println!("cargo:rerun-if-changed={}", dir.display());
+    let mut examples = Vec::new();
+    for file in fs::read_dir(&dir).into_iter().flatten() {
+        let path = file.unwrap().path();
+        if path.extension().is_none_or(|e| e != "txt") {
+            continue;
+        }
+        let name = path.file_stem().unwrap().to_str().unwrap();
+        let (day, n) = name
+            .strip_prefix("day")
+            .and_then(|s| s.split_once('-'))
+            .and_then(|(d, n)| Some((d.parse::<u8>().ok()?, n.parse::<u32>().ok()?)))
+            .unwrap_or_else(|| panic!("{:?} is not named like `day1-1.txt`", path));
+        let answers = path.with_extension("toml");
+        println!("cargo:rerun-if-changed={}", answers.display());
+        let answers: toml::Table = fs::read_to_string(&answers)
+            .unwrap_or_else(|e| panic!("reading {:?}: {}", answers, e))
+            .parse()
+            .unwrap_or_else(|e| panic!("parsing {:?}: {}", answers, e));
+        let ignore = answers
+            .get("ignore")
+            .and_then(|v| v.as_str())
+            .map(str::to_owned);
+        examples.push((day, n, ignore));
+    }
+    examples.sort();
+
+    let tests: String = examples
+        .into_iter()
+        .map(|(day, n, ignore)| {
+            let ignore = ignore.map_or(String::new(), |why| format!("#[ignore = {:?}] ", why));
+            format!(
+                "example!({}y{}_day{}_{}, {}, \"{}/day{}-{}\");\n",
+                ignore, year, day, n, day, year, day, n
+            )
+        })
+        .collect();
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("examples.rs");
+    fs::write(out, tests).unwrap();
+}
@@ -0,0 +1,18 @@
+#!/bin/bash
+# Seeds the corpus of each fuzz target with the real input of its day and a few small
+# generated ones, then run for example
+# `cargo +nightly fuzz run day13` in aoc2022.
+
+cd "$(dirname "$0")/../.." || exit 1
+cargo build -q --release -p aoc || exit 1
+
+for day in $(seq 1 25); do
+    corpus=aoc2022/fuzz/corpus/day${day}
+    mkdir -p "$corpus"
+    if [[ -f input/2022/day${day}.txt ]]; then
+        cp input/2022/day${day}.txt "$corpus/input"
+    fi
+    for seed in 0 1 2; do
+        ./target/release/aoc2022 gen --day "$day" --seed "$seed" --size 0 > "$corpus/gen-${seed}"
+    done
+done
@@ -0,0 +1,77 @@
+use aoc_common::{
+    parse::{ParseError, Parser},
+    solution::Solution,
+    stream::{Lines, StreamError, Streamed},
+};
+use std::io::BufRead;
+
+#[aoc_generator(day1)]
+pub fn input_generator(input: &str) -> Result<Vec<u64>, ParseError> {
+    let p = Parser::new(1, input);
+    input
+        .split("\n\n")
+        // small enough for the sums not to overflow
+        .map(|l| {
+            l.lines()
+                .map(|ll| p.parse::<u32>(ll, "calories").map(u64::from))
+                .sum()
+        })
+        .collect()
+}
+
+#[aoc(day1, part1)]
+pub fn part1(input: &[u64]) -> u64 {
+    *input.iter().max().unwrap()
+}
+#[aoc(day1, part2)]
+pub fn part2(input: &[u64]) -> u64 {
+    let mut i = input.to_owned();
+    i.sort();
+    i.iter().rev().take(3).sum()
+}
+
+/// Both parts, keeping only the three largest blocks seen so far.
+pub fn stream(input: impl BufRead) -> Result<(u64, u64), StreamError> {
+    let mut lines = Lines::new(1, input);
+    let (mut top, mut block) = ([0_u64; 3], 0);
+    let mut after_blank = false;
+    let mut end_block = |block: u64| {
+        if let Some(i) = top.iter().position(|&t| block > t) {
+            top[i..].rotate_right(1);
+            top[i] = block;
+        }
+    };
+    while let Some((p, l)) = lines.next_line()? {
+        match l {
+            // a single blank line between the blocks
+            "" if after_blank => return Err(p.error(l, "calories").into()),
+            "" => end_block(std::mem::take(&mut block)),
+            _ => block += u64::from(p.parse::<u32>(l, "calories")?),
+        }
+        after_blank = l.is_empty();
+    }
+    end_block(block);
+    Ok((top[0], top.iter().sum()))
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    type Input = Vec<u64>;
+    type Output = u64;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input_generator(input)
+    }
+    fn part1(input: &Self::Input, _: &()) -> Self::Output {
+        part1(input)
+    }
+    fn part2(input: &Self::Input, _: &()) -> Self::Output {
+        part2(input)
+    }
+    fn stream(input: &mut dyn BufRead, _: &()) -> Streamed<Self::Output> {
+        Some(stream(input))
+    }
+}
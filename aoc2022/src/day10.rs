@@ -0,0 +1,140 @@
+use aoc_common::{
+    answer::Answer,
+    grid::Grid,
+    params::params,
+    parse::{ParseError, Parser},
+    solution::Solution,
+    stream::{Lines, StreamError, Streamed},
+};
+use std::io::BufRead;
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Cmd {
+    Noop,
+    Addx(i32),
+}
+use Cmd::*;
+
+fn parse_cmd(p: &Parser, l: &str) -> Result<Cmd, ParseError> {
+    match l.split_once(' ') {
+        None if l == "noop" => Ok(Noop),
+        Some(("addx", i)) => Ok(Addx(p.parse(i, "a number")?)),
+        _ => Err(p.error(l, "`noop` or `addx N`")),
+    }
+}
+
+#[aoc_generator(day10)]
+pub fn input_generator(input: &str) -> Result<Vec<Cmd>, ParseError> {
+    let p = Parser::new(10, input);
+    p.lines().map(|l| parse_cmd(&p, l)).collect()
+}
+
+/// The register, the signal strength and the screen, run one command at a time for both parts.
+struct Cpu<'a> {
+    cycles: &'a [usize],
+    reg: isize,
+    /// Cycles done so far.
+    cycle: usize,
+    strength: isize,
+    screen: Grid<char>,
+}
+
+impl<'a> Cpu<'a> {
+    /// Sums the signal strength during `cycles`.
+    fn new(cycles: &'a [usize]) -> Self {
+        Cpu {
+            cycles,
+            reg: 1,
+            cycle: 0,
+            strength: 0,
+            screen: Grid::new(40, 6, '.'),
+        }
+    }
+
+    fn tick(&mut self) {
+        let x = (self.cycle % 40) as isize;
+        // nothing is drawn after the last row of the screen
+        if (self.reg - x).abs() < 2 {
+            if let Some(pixel) = self.screen.get_mut((self.cycle % 40, self.cycle / 40)) {
+                *pixel = '#';
+            }
+        }
+        self.cycle += 1;
+        if self.cycles.contains(&self.cycle) {
+            self.strength += self.reg * self.cycle as isize;
+        }
+    }
+
+    fn exec(&mut self, cmd: &Cmd) {
+        self.tick();
+        if let Addx(x) = cmd {
+            self.tick();
+            self.reg += *x as isize;
+        }
+    }
+}
+
+pub fn run(input: &[Cmd], cycles: &[usize]) -> isize {
+    let mut cpu = Cpu::new(cycles);
+    input.iter().for_each(|c| cpu.exec(c));
+    cpu.strength
+}
+pub fn run2(input: &[Cmd]) -> Grid<char> {
+    let mut cpu = Cpu::new(&[]);
+    input.iter().for_each(|c| cpu.exec(c));
+    cpu.screen
+}
+
+/// Both parts, running each command as it is read.
+pub fn stream(input: impl BufRead, params: &Params) -> Result<(isize, Grid<char>), StreamError> {
+    let mut lines = Lines::new(10, input);
+    let mut cpu = Cpu::new(&params.cycles);
+    while let Some((p, l)) = lines.next_line()? {
+        cpu.exec(&parse_cmd(&p, l)?);
+    }
+    Ok((cpu.strength, cpu.screen))
+}
+
+params! {
+    pub struct Params {
+        /// Cycles during which the signal strength is summed.
+        pub cycles: Vec<usize> = vec![20, 60, 100, 140, 180, 220],
+    }
+}
+
+#[aoc(day10, part1)]
+pub fn part1(input: &[Cmd]) -> isize {
+    part1_with(input, &Params::default())
+}
+pub fn part1_with(input: &[Cmd], params: &Params) -> isize {
+    run(input, &params.cycles)
+}
+#[aoc(day10, part2)]
+pub fn part2(input: &[Cmd]) -> Grid<char> {
+    run2(input)
+}
+pub fn part2_with(input: &[Cmd], _: &Params) -> Grid<char> {
+    part2(input)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input = Vec<Cmd>;
+    type Output = Answer;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input_generator(input)
+    }
+    fn part1(input: &Self::Input, params: &Params) -> Self::Output {
+        part1_with(input, params).into()
+    }
+    fn part2(input: &Self::Input, params: &Params) -> Self::Output {
+        part2_with(input, params).into()
+    }
+    fn stream(input: &mut dyn BufRead, params: &Params) -> Streamed<Self::Output> {
+        Some(stream(input, params).map(|(part1, part2)| (part1.into(), part2.into())))
+    }
+}
@@ -0,0 +1,195 @@
+use aoc_common::{
+    answer::Answer,
+    parse::{ParseError, Parser},
+    solution::Solution,
+};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    static ref R1: Regex = Regex::new(r"Monkey (\d+):").unwrap();
+    static ref R2: Regex = Regex::new(r"  Starting items: ([0-9, ]+)").unwrap();
+    static ref R3: Regex = Regex::new(r"  Operation: new = old (.) ([a-z0-9]+)").unwrap();
+    static ref R4: Regex = Regex::new(r"  Test: divisible by (\d+)").unwrap();
+    static ref R5: Regex = Regex::new(r"    If true: throw to monkey (\d+)").unwrap();
+    static ref R6: Regex = Regex::new(r"    If false: throw to monkey (\d+)").unwrap();
+}
+
+/// Worry levels, operands and the product of the divisors stay below, so that the square of a
+/// worry level still fits in a `u64`.
+const MAX_WORRY: usize = 1 << 32;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Op {
+    Add(usize),
+    Mul(usize),
+    MulSelf,
+}
+use Op::*;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Monkey {
+    items: Vec<usize>,
+    op: Op,
+    div_by: usize,
+    throw_to: (usize, usize),
+}
+
+impl Monkey {
+    /// `product` is the one of the divisors of the monkeys before, and gets this one's too.
+    fn parse(p: &Parser, s: &str, n: usize, product: &mut usize) -> Result<Monkey, ParseError> {
+        let mut lines = s.lines();
+        let mut next = |r: &Regex, expected: &str| {
+            let l = lines
+                .next()
+                .ok_or_else(|| p.error(Parser::end(s), expected))?;
+            r.captures(l).ok_or_else(|| p.error(l, expected))
+        };
+
+        next(&R1, "`Monkey N:`")?;
+        let l2 = next(&R2, "`  Starting items: A, B, ...`")?;
+        let l3 = next(&R3, "`  Operation: new = old OP X`")?;
+        let l4 = next(&R4, "`  Test: divisible by N`")?;
+        let l5 = next(&R5, "`    If true: throw to monkey N`")?;
+        let l6 = next(&R6, "`    If false: throw to monkey N`")?;
+
+        let items = l2
+            .get(1)
+            .unwrap()
+            .as_str()
+            .split(", ")
+            .map(|i| Ok(p.parse::<u32>(i, "a worry level")? as usize))
+            .collect::<Result<_, _>>()?;
+        let (op, arg) = (l3.get(1).unwrap().as_str(), l3.get(2).unwrap().as_str());
+        let op = match (op, arg) {
+            ("*", "old") => MulSelf,
+            ("+", x) => Add(p.parse::<u32>(x, "a number")? as usize),
+            ("*", x) => Mul(p.parse::<u32>(x, "a number or `old`")? as usize),
+            _ => return Err(p.error(op, "`+` or `*`")),
+        };
+        let div_by = l4.get(1).unwrap().as_str();
+        let div_by = match p.parse::<usize>(div_by, "a divisor")? {
+            0 => return Err(p.error(div_by, "a non-zero divisor")),
+            d => d,
+        };
+        *product = product
+            .checked_mul(div_by)
+            .filter(|&d| d <= MAX_WORRY)
+            .ok_or_else(|| {
+                p.error(
+                    l4.get(1).unwrap().as_str(),
+                    "divisors with a product up to 2^32",
+                )
+            })?;
+        let monkey = |c: regex::Captures| {
+            let to = c.get(1).unwrap().as_str();
+            match p.parse::<usize>(to, "a monkey")? {
+                i if i < n => Ok(i),
+                _ => Err(p.error(to, format!("a monkey below {}", n))),
+            }
+        };
+        Ok(Monkey {
+            items,
+            op,
+            div_by,
+            throw_to: (monkey(l5)?, monkey(l6)?),
+        })
+    }
+}
+
+#[aoc_generator(day11)]
+pub fn input_generator(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let p = Parser::new(11, input);
+    let n = input.split("\n\n").count();
+    let mut product = 1;
+    let monkeys = input
+        .split("\n\n")
+        .map(|s| Monkey::parse(&p, s, n, &mut product))
+        .collect::<Result<Vec<_>, _>>()?;
+    if n < 2 {
+        return Err(p.error(Parser::end(input), "a second monkey"));
+    }
+    Ok(monkeys)
+}
+
+pub fn one_round(monkeys: &mut [Monkey], div: bool, pgcm: usize) -> Vec<usize> {
+    let mut res = vec![0; monkeys.len()];
+    for i in 0..monkeys.len() {
+        for j in 0..monkeys[i].items.len() {
+            let item = monkeys[i].items[j];
+            let throw_to = monkeys[i].throw_to;
+
+            let mut res = match monkeys[i].op {
+                Add(x) => item + x,
+                Mul(x) => item * x,
+                MulSelf => item * item,
+            };
+
+            if div {
+                res /= 3;
+            }
+
+            let res = res % pgcm;
+
+            if res.is_multiple_of(monkeys[i].div_by) {
+                monkeys[throw_to.0].items.push(res);
+            } else {
+                monkeys[throw_to.1].items.push(res);
+            }
+        }
+        res[i] += monkeys[i].items.len();
+        monkeys[i].items = vec![];
+    }
+    res
+}
+
+/// `None` when the monkey business overflows, with a lot of items.
+#[aoc(day11, part1)]
+pub fn part1(input: &[Monkey]) -> Option<usize> {
+    let mut input = input.to_vec();
+    let pgcm = input.iter().fold(1, |acc, m| acc * m.div_by);
+    let mut res = (0..20).fold(vec![0; input.len()], |acc: Vec<usize>, _| {
+        acc.iter()
+            .zip(one_round(&mut input, true, pgcm))
+            .map(|(&x, y)| x + y)
+            .collect()
+    });
+    res.sort_by(|a, b| b.partial_cmp(a).unwrap());
+    res[0].checked_mul(res[1])
+}
+
+/// `None` when the monkey business overflows, with a lot of items.
+#[aoc(day11, part2)]
+pub fn part2(input: &[Monkey]) -> Option<usize> {
+    let mut input = input.to_vec();
+    let pgcm = input.iter().fold(1, |acc, m| acc * m.div_by);
+    let mut res = (0..10000).fold(vec![0; input.len()], |acc: Vec<usize>, _| {
+        acc.iter()
+            .zip(one_round(&mut input, false, pgcm))
+            .map(|(&x, y)| x + y)
+            .collect()
+    });
+    res.sort_by(|a, b| b.partial_cmp(a).unwrap());
+    res[0].checked_mul(res[1])
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Input = Vec<Monkey>;
+    type Output = Answer;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input_generator(input)
+    }
+    fn part1(input: &Self::Input, _: &()) -> Self::Output {
+        part1(input).into()
+    }
+    fn part2(input: &Self::Input, _: &()) -> Self::Output {
+        part2(input).into()
+    }
+}
@@ -0,0 +1,106 @@
+use aoc_common::{
+    grid::{Grid, Pos},
+    parse::{ParseError, Parser},
+    search::{self, Graph},
+    solution::Solution,
+};
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Map {
+    map: Grid<u8>,
+    start: Pos,
+}
+
+impl Map {
+    fn get_height(&self, pos: Pos) -> u8 {
+        match self.map[pos] {
+            b'S' => b'a',
+            b'E' => b'z',
+            c => c,
+        }
+    }
+
+    pub fn reverse_bfs(&self, end: u8) -> usize {
+        search::bfs(&Descent { map: self, end }, self.start)
+            .goal_distance()
+            .unwrap_or_else(|| unreachable!())
+    }
+}
+
+/// Walking back from `E` to the first square of height `end`.
+struct Descent<'a> {
+    map: &'a Map,
+    end: u8,
+}
+
+impl Graph for Descent<'_> {
+    type Node = Pos;
+
+    fn successors(&self, &pos: &Pos) -> Vec<(Pos, usize)> {
+        let h = self.map.get_height(pos);
+        self.map
+            .map
+            .neighbours4(pos)
+            .filter(|&p| h - 1 <= self.map.get_height(p))
+            .map(|p| (p, 1))
+            .collect()
+    }
+    fn is_goal(&self, &pos: &Pos) -> bool {
+        // `S` is also a square of height `a`
+        self.map.map[pos] == self.end || self.map.get_height(pos) == self.end
+    }
+}
+
+#[aoc_generator(day12)]
+pub fn input_generator(input: &str) -> Result<Map, ParseError> {
+    let p = Parser::new(12, input);
+    let map = Grid::parse(&p, input, "a height, `S` or `E`", |c| {
+        matches!(c, 'a'..='z' | 'S' | 'E').then_some(c as u8)
+    })?;
+    let start = map
+        .find(|&c| c == b'E')
+        .ok_or_else(|| p.error(Parser::end(input), "an `E` square"))?;
+    let s = input
+        .find('S')
+        .ok_or_else(|| p.error(Parser::end(input), "an `S` square"))?;
+
+    let map = Map { map, start };
+    // then there is also a square of height `a` to reach in part 2: `S`
+    let descent = Descent {
+        map: &map,
+        end: b'S',
+    };
+    if search::bfs(&descent, start).goal_distance().is_none() {
+        return Err(p.error(&input[s..], "an `S` square from which `E` can be reached"));
+    }
+    Ok(map)
+}
+
+#[aoc(day12, part1)]
+pub fn part1(map: &Map) -> usize {
+    map.reverse_bfs(b'S')
+}
+
+#[aoc(day12, part2)]
+pub fn part2(map: &Map) -> usize {
+    map.reverse_bfs(b'a')
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Input = Map;
+    type Output = usize;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input_generator(input)
+    }
+    fn part1(input: &Self::Input, _: &()) -> Self::Output {
+        part1(input)
+    }
+    fn part2(input: &Self::Input, _: &()) -> Self::Output {
+        part2(input)
+    }
+}
@@ -0,0 +1,165 @@
+use aoc_common::{
+    parse::{ParseError, Parser},
+    solution::Solution,
+};
+use std::iter::once;
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Value {
+    Int(usize),
+    List(Vec<Box<Value>>),
+}
+use Value::*;
+
+impl Value {
+    pub fn is_less(&self, right: &Value) -> Option<bool> {
+        match (self, right) {
+            (Int(l), Int(r)) if l != r => Some(l < r),
+            (Int(_), Int(_)) => None,
+            (Int(l), List(_)) => List(vec![Box::new(Int(*l))]).is_less(right),
+            (List(_), Int(r)) => self.is_less(&List(vec![Box::new(Int(*r))])),
+            (List(l), List(r)) => {
+                for i in 0..l.len() {
+                    if r.get(i).is_none() {
+                        return Some(false);
+                    }
+                    if let Some(res) = l.get(i).unwrap().is_less(r.get(i).unwrap()) {
+                        return Some(res);
+                    }
+                }
+                if l.len() == r.len() {
+                    None
+                } else {
+                    Some(true)
+                }
+            }
+        }
+    }
+}
+
+/// Deepest nesting of lists accepted, so that parsing and comparing never run out of stack.
+const MAX_DEPTH: usize = 256;
+
+fn parse_value<'a>(p: &Parser, s: &'a str, depth: usize) -> Result<(Value, &'a str), ParseError> {
+    let Some(mut rest) = s.strip_prefix('[') else {
+        let end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+        if end == 0 {
+            return Err(p.error(s, "a number or `[`"));
+        }
+        return Ok((Int(p.parse(&s[..end], "a number")?), &s[end..]));
+    };
+    if depth == MAX_DEPTH {
+        return Err(p.error(s, format!("at most {} nested lists", MAX_DEPTH)));
+    }
+    let mut list = Vec::new();
+    if let Some(rest) = rest.strip_prefix(']') {
+        return Ok((List(list), rest));
+    }
+    loop {
+        let (v, r) = parse_value(p, rest, depth + 1)?;
+        list.push(Box::new(v));
+        if let Some(r) = r.strip_prefix(',') {
+            rest = r;
+        } else if let Some(r) = r.strip_prefix(']') {
+            return Ok((List(list), r));
+        } else {
+            return Err(p.error(r, "`,` or `]`"));
+        }
+    }
+}
+
+pub fn parse(p: &Parser, line: &str) -> Result<Value, ParseError> {
+    match parse_value(p, line, 0)? {
+        (v, "") => Ok(v),
+        (_, rest) => Err(p.error(rest, "the end of the packet")),
+    }
+}
+
+#[aoc_generator(day13)]
+pub fn input_generator(input: &str) -> Result<Vec<(Value, Value)>, ParseError> {
+    let p = Parser::new(13, input);
+    input
+        .split("\n\n")
+        .map(|text| {
+            let mut lines = text.lines();
+            let mut next = || {
+                let l = lines
+                    .next()
+                    .ok_or_else(|| p.error(Parser::end(text), "a packet"))?;
+                parse(&p, l)
+            };
+            let pair = (next()?, next()?);
+            if pair.0.is_less(&pair.1).is_none() {
+                return Err(p.error(text, "two packets in some order"));
+            }
+            match lines.next() {
+                Some(l) => Err(p.error(l, "an empty line between pairs")),
+                None => Ok(pair),
+            }
+        })
+        .collect()
+}
+
+#[aoc(day13, part1)]
+pub fn part1(input: &[(Value, Value)]) -> usize {
+    input
+        .iter()
+        .enumerate()
+        .filter_map(|(i, p)| {
+            if p.0.is_less(&p.1).unwrap() {
+                Some(i + 1)
+            } else {
+                None
+            }
+        })
+        .sum()
+}
+
+#[aoc(day13, part2)]
+pub fn part2(input: &[(Value, Value)]) -> usize {
+    let mut pkts: Vec<&Value> = input
+        .iter()
+        .flat_map(|p| once(&p.0).chain(once(&p.1)))
+        .collect();
+
+    let divider = |s| parse(&Parser::new(13, s), s).unwrap();
+    let (two, six) = (divider("[[2]]"), divider("[[6]]"));
+    pkts.push(&two);
+    pkts.push(&six);
+
+    pkts.sort_by(|a, b| match a.is_less(b) {
+        Some(true) => std::cmp::Ordering::Less,
+        Some(false) => std::cmp::Ordering::Greater,
+        None => std::cmp::Ordering::Equal,
+    });
+
+    pkts.iter()
+        .enumerate()
+        .filter_map(|(i, v)| {
+            if two.is_less(v).is_none() || six.is_less(v).is_none() {
+                Some(i + 1)
+            } else {
+                None
+            }
+        })
+        .product()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Input = Vec<(Value, Value)>;
+    type Output = usize;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input_generator(input)
+    }
+    fn part1(input: &Self::Input, _: &()) -> Self::Output {
+        part1(input)
+    }
+    fn part2(input: &Self::Input, _: &()) -> Self::Output {
+        part2(input)
+    }
+}
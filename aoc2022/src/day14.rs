@@ -0,0 +1,233 @@
+use aoc_common::{
+    grid::{Grid, Pos},
+    parse::{ParseError, Parser},
+    simulation::Simulation,
+    solution::Solution,
+    viz::Draw,
+};
+use std::cmp::min;
+
+use itertools::Itertools;
+
+/// The sand falls from column 500, and piles up no wider than its depth on both sides.
+const MAX_X: usize = 1000;
+/// Deepest rock, so that filling the cave up to the start takes a reasonable time.
+const MAX_Y: usize = 500;
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Map {
+    m: Grid<bool>,
+    lowest_line: usize,
+    start: Pos,
+}
+
+#[aoc_generator(day14)]
+pub fn input_generator(input: &str) -> Result<Map, ParseError> {
+    let p = Parser::new(14, input);
+    let mut m = Vec::new();
+    let mut lowest = 0_usize;
+    for l in p.lines() {
+        let points = l
+            .split(" -> ")
+            .map(|c| {
+                let (x, y) = p.split_once(c, ",", "a point like `498,4`")?;
+                let x = p.parse::<usize>(x, "a coordinate")?;
+                let y = p.parse::<usize>(y, "a coordinate")?;
+                if x > MAX_X {
+                    return Err(p.error(c, format!("a column up to {}", MAX_X)));
+                }
+                if y > MAX_Y {
+                    return Err(p.error(c, format!("a depth up to {}", MAX_Y)));
+                }
+                Ok((c, (x as isize, y as isize)))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        for ((_, p1), (c, p2)) in points.into_iter().tuple_windows() {
+            let min = min(p1.1, p2.1);
+            if min as usize > lowest {
+                lowest = min as usize;
+            }
+            if p1.0 == p2.0 {
+                let dy = (p2.1 - p1.1).signum();
+                let mut ty = p2.1;
+                while ty != p1.1 {
+                    m.push((p1.0 as usize, (ty) as usize));
+                    ty -= dy;
+                }
+                m.push((p1.0 as usize, p1.1 as usize));
+            } else if p1.1 == p2.1 {
+                let dx = (p2.0 - p1.0).signum();
+                let mut tx = p2.0;
+                while tx != p1.0 {
+                    m.push(((tx) as usize, p1.1 as usize));
+                    tx -= dx;
+                }
+                m.push((p1.0 as usize, p1.1 as usize));
+            } else {
+                return Err(p.error(c, "a point on the same row or column"));
+            }
+        }
+    }
+    // big enough for the sand to pile up to the start on the floor
+    let w = m
+        .iter()
+        .map(|p| p.0)
+        .max()
+        .unwrap_or(0)
+        .max(500 + lowest + 3)
+        + 1;
+    let h = m.iter().map(|p| p.1).max().unwrap_or(0).max(lowest + 2) + 1;
+    let mut grid = Grid::new(w, h, false);
+    for pos in m {
+        grid[pos] = true;
+    }
+    Ok(Map {
+        m: grid,
+        lowest_line: lowest,
+        start: (500, 0),
+    })
+}
+
+impl Map {
+    pub fn drop_sand(&mut self, floor: bool) -> usize {
+        let mut sand = Sand::new(self.clone(), floor);
+        let count = sand.run_until(|_| false);
+        *self = sand.map;
+        count
+    }
+
+    pub fn get(&self, p: Pos, floor: bool) -> Option<()> {
+        if floor && p.1 == self.lowest_line + 2 {
+            return Some(());
+        }
+        self.m.get(p).copied().filter(|&b| b).and(Some(()))
+    }
+}
+
+/// Sand falling from the start, one grain per step.
+#[derive(Clone)]
+pub struct Sand {
+    map: Map,
+    /// The map before any sand, to tell rocks from sand.
+    rocks: Grid<bool>,
+    floor: bool,
+    done: bool,
+}
+
+impl Sand {
+    pub fn new(map: Map, floor: bool) -> Self {
+        Sand {
+            rocks: map.m.clone(),
+            map,
+            floor,
+            done: false,
+        }
+    }
+}
+
+impl Simulation for Sand {
+    type State = Grid<bool>;
+    type Snapshot = Sand;
+
+    /// Over once a grain falls below the lowest line, or rests at the start.
+    fn step(&mut self) -> bool {
+        if self.done {
+            return false;
+        }
+        let (map, floor) = (&mut self.map, self.floor);
+        let mut pos = map.start;
+        loop {
+            pos.1 += 1;
+            if map.get(pos, floor).is_some() {
+                pos.0 -= 1;
+                if map.get(pos, floor).is_some() {
+                    pos.0 += 2;
+                    if map.get(pos, floor).is_some() {
+                        let pos = (pos.0 - 1, pos.1 - 1);
+                        map.m[pos] = true;
+                        self.done = pos == map.start;
+                        return true;
+                    }
+                }
+            }
+            if !floor && pos.1 >= map.lowest_line {
+                self.done = true;
+                return false;
+            }
+        }
+    }
+    fn state(&self) -> &Grid<bool> {
+        &self.map.m
+    }
+    fn snapshot(&self) -> Sand {
+        self.clone()
+    }
+    fn restore(&mut self, snapshot: Sand) {
+        *self = snapshot;
+    }
+}
+
+/// Rocks as `#`, sand as `o` and the source as `+`, around the columns the sand can reach.
+impl Draw for Sand {
+    fn draw(&self) -> Grid<char> {
+        let floor = self.map.lowest_line + 2;
+        let start = self.map.start;
+        let (min_x, max_x, h) = if self.floor {
+            (
+                start.0.saturating_sub(floor + 1),
+                start.0 + floor + 1,
+                floor + 1,
+            )
+        } else {
+            let rocks = self.rocks.positions().filter(|&p| self.rocks[p]);
+            let (min_x, max_x, max_y) = rocks.fold((start.0, start.0, start.1), |b, p| {
+                (b.0.min(p.0), b.1.max(p.0), b.2.max(p.1))
+            });
+            (min_x.saturating_sub(1), max_x + 1, max_y + 2)
+        };
+        let mut g = Grid::new(max_x - min_x + 1, h, '.');
+        for pos in g.positions().collect::<Vec<_>>() {
+            let p = (pos.0 + min_x, pos.1);
+            g[pos] = if self.rocks.get(p) == Some(&true) || (self.floor && p.1 == floor) {
+                '#'
+            } else if self.map.m.get(p) == Some(&true) {
+                'o'
+            } else if p == self.map.start {
+                '+'
+            } else {
+                '.'
+            };
+        }
+        g
+    }
+}
+
+#[aoc(day14, part1)]
+pub fn part1(input: &Map) -> usize {
+    Sand::new(input.clone(), false).run_until(|_| false)
+}
+
+#[aoc(day14, part2)]
+pub fn part2(input: &Map) -> usize {
+    Sand::new(input.clone(), true).run_until(|_| false)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Input = Map;
+    type Output = usize;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input_generator(input)
+    }
+    fn part1(input: &Self::Input, _: &()) -> Self::Output {
+        part1(input)
+    }
+    fn part2(input: &Self::Input, _: &()) -> Self::Output {
+        part2(input)
+    }
+}
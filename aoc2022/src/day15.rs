@@ -0,0 +1,142 @@
+use aoc_common::{
+    answer::Answer,
+    params::params,
+    parse::{ParseError, Parser},
+    solution::Solution,
+};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::HashSet;
+
+lazy_static! {
+    static ref R: Regex =
+        Regex::new(r"Sensor at x=(-?\d+), y=(-?\d+): closest beacon is at x=(-?\d+), y=(-?\d+)")
+            .unwrap();
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Sensor {
+    pos: (isize, isize),
+    closest: (isize, isize),
+    dist: isize,
+}
+
+impl Sensor {
+    pub fn is_inside_range(&self, p: (isize, isize)) -> bool {
+        self.closest != p && self.covers(p)
+    }
+
+    /// Whether `p` is not farther than the closest beacon.
+    pub fn covers(&self, p: (isize, isize)) -> bool {
+        self.dist as usize >= self.pos.0.abs_diff(p.0) + self.pos.1.abs_diff(p.1)
+    }
+}
+
+#[aoc_generator(day15)]
+pub fn input_generator(input: &str) -> Result<Vec<Sensor>, ParseError> {
+    let p = Parser::new(15, input);
+    p.lines()
+        .map(|l| {
+            let c = R
+                .captures(l)
+                .ok_or_else(|| p.error(l, "`Sensor at x=X, y=Y: closest beacon is at x=X, y=Y`"))?;
+            // small enough for the distances and the tuning frequency not to overflow
+            let n = |i| {
+                p.parse::<i32>(c.get(i).unwrap().as_str(), "a coordinate")
+                    .map(|c| c as isize)
+            };
+            let mut s = Sensor {
+                pos: (n(1)?, n(2)?),
+                closest: (n(3)?, n(4)?),
+                dist: 0,
+            };
+            s.dist = (s.pos.0.abs_diff(s.closest.0) + s.pos.1.abs_diff(s.closest.1)) as isize;
+            Ok(s)
+        })
+        .collect()
+}
+
+/// `None` when the row is too far for its distance to a sensor to be an `isize`.
+pub fn part1_with(input: &[Sensor], params: &Params) -> Option<usize> {
+    let n = params.row;
+    let mut covered = Vec::new();
+    for s in input {
+        let w = s.dist - s.pos.1.checked_sub(n)?.checked_abs()?;
+        if w >= 0 {
+            covered.push((s.pos.0 - w, s.pos.0 + w));
+        }
+    }
+    covered.sort_unstable();
+    let (mut count, mut next) = (0, isize::MIN);
+    for (a, b) in covered {
+        let a = a.max(next);
+        if a <= b {
+            count += (b - a + 1) as usize;
+            next = b + 1;
+        }
+    }
+    // a beacon can be where there is already one
+    let beacons = input
+        .iter()
+        .filter(|s| s.closest.1 == n)
+        .map(|s| s.closest.0)
+        .collect::<HashSet<_>>();
+    Some(count - beacons.len())
+}
+
+/// Row by row, jumping over the positions covered by each sensor. `None` also when the tuning
+/// frequency overflows.
+pub fn part2_with(input: &[Sensor], params: &Params) -> Option<isize> {
+    let n = params.bound;
+    let (x, y) = (0..=n).find_map(|y| {
+        let mut x = 0;
+        while x <= n {
+            match input.iter().find(|s| s.covers((x, y))) {
+                // covered, so the row is at most `s.dist` away
+                Some(s) => x = s.pos.0 + s.dist - s.pos.1.abs_diff(y) as isize + 1,
+                None => return Some((x, y)),
+            }
+        }
+        None
+    })?;
+    x.checked_mul(4000000)?.checked_add(y)
+}
+
+params! {
+    pub struct Params {
+        /// Row where the positions without beacon are counted in part 1.
+        pub row: isize = 2000000,
+        /// Biggest coordinate of the distress beacon in part 2.
+        pub bound: isize = 4000000,
+    }
+}
+
+#[aoc(day15, part1)]
+pub fn part1(input: &[Sensor]) -> Option<usize> {
+    part1_with(input, &Params::default())
+}
+
+#[aoc(day15, part2)]
+pub fn part2(input: &[Sensor]) -> Option<isize> {
+    part2_with(input, &Params::default())
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    type Input = Vec<Sensor>;
+    type Output = Answer;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input_generator(input)
+    }
+    fn part1(input: &Self::Input, params: &Params) -> Self::Output {
+        part1_with(input, params).into()
+    }
+    fn part2(input: &Self::Input, params: &Params) -> Self::Output {
+        part2_with(input, params).into()
+    }
+}
@@ -0,0 +1,217 @@
+use aoc_common::{
+    answer::Answer,
+    params::params,
+    parse::{ParseError, Parser},
+    solution::Solution,
+};
+use itertools::{iproduct, Itertools};
+use ndarray::Array3;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    static ref R: Regex = Regex::new(
+        r"Valve ([A-Z][A-Z]) has flow rate=(\d+); tunnel(?:s?) lead(?:s?) to valve(?:s?) (.*)"
+    )
+    .unwrap();
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Valve {
+    pub(crate) name: String,
+    pub(crate) rate: usize,
+    pub(crate) tunnels: Vec<String>,
+}
+
+/// Most valves with a flow rate, as part 2 goes through every pair of sets of them.
+const MAX_WORKING: usize = 15;
+/// Most cells in the table of [`get_totals`], a little more than 256 MiB.
+const MAX_CELLS: usize = 1 << 27;
+
+#[aoc_generator(day16)]
+pub fn input_generator(input: &str) -> Result<(Vec<Valve>, usize, usize), ParseError> {
+    let p = Parser::new(16, input);
+    let lines = p
+        .lines()
+        .map(|l| {
+            R.captures(l).ok_or_else(|| {
+                p.error(
+                    l,
+                    "`Valve XX has flow rate=N; tunnels lead to valves YY, ZZ`",
+                )
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let names: Vec<&str> = lines.iter().map(|l| l.get(1).unwrap().as_str()).collect();
+
+    let i: Vec<Valve> = lines
+        .iter()
+        .map(|l| {
+            let tunnels = l
+                .get(3)
+                .unwrap()
+                .as_str()
+                .trim()
+                .split(", ")
+                .map(|s| match names.contains(&s) {
+                    true => Ok(s.to_string()),
+                    false => Err(p.error(s, "a known valve")),
+                })
+                .collect::<Result<_, _>>()?;
+            Ok(Valve {
+                name: l[1].to_string(),
+                rate: p.parse::<u16>(l.get(2).unwrap().as_str(), "a flow rate")? as usize,
+                tunnels,
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .sorted_by(|a, b| b.rate.cmp(&a.rate))
+        .collect();
+    let real_pipes = i.iter().filter(|v| v.rate > 0).count();
+    if real_pipes > MAX_WORKING {
+        let l = lines
+            .iter()
+            .filter(|l| l[2].bytes().any(|b| b != b'0'))
+            .nth(MAX_WORKING)
+            .unwrap();
+        return Err(p.error(
+            l.get(2).unwrap().as_str(),
+            format!("at most {} valves with a flow rate", MAX_WORKING),
+        ));
+    }
+    let start = i
+        .iter()
+        .enumerate()
+        .find(|(_, v)| v.name == "AA")
+        .ok_or_else(|| p.error(Parser::end(input), "a valve named `AA`"))?
+        .0;
+    Ok((i, real_pipes, start))
+}
+
+/// Whether the table of [`get_totals`] for `minutes` is small enough to be allocated, and its
+/// totals small enough for a `u16`.
+fn fits(input: &(Vec<Valve>, usize, usize), minutes: usize) -> bool {
+    let (valves, n_real, _) = input;
+    let rates: usize = valves.iter().map(|v| v.rate).sum();
+    minutes > 0
+        && minutes
+            .checked_mul(valves.len() << n_real)
+            .is_some_and(|cells| cells <= MAX_CELLS)
+        && rates
+            .checked_mul(minutes)
+            .is_some_and(|max| max <= u16::MAX as usize)
+}
+
+pub fn get_totals(valves: &[Valve], n_real: usize, minutes: usize) -> Array3<u16> {
+    let next_valves: Vec<Vec<_>> = valves
+        .iter()
+        .map(|v| {
+            v.tunnels
+                .iter()
+                .map(|to| {
+                    valves
+                        .iter()
+                        .enumerate()
+                        .find(|(_, v)| v.name == *to)
+                        .unwrap()
+                        .0
+                })
+                .collect()
+        })
+        .collect();
+
+    let mut totals = Array3::<u16>::zeros([minutes, valves.len(), 1 << n_real]);
+
+    for (m, v, opened) in iproduct!(1..minutes, 0..valves.len(), 0..1 << n_real) {
+        // only the valves with a flow rate, which come first, are worth opening
+        let bit_v = if v < n_real { 1 << v } else { 0 };
+        let mut total = totals[(m, v, opened)];
+        if bit_v & opened != 0 {
+            total = std::cmp::max(
+                total,
+                totals[(m - 1, v, opened - bit_v)] + (valves[v].rate * m) as u16,
+            );
+        }
+        if let Some(t) = next_valves[v]
+            .iter()
+            .map(|&vi| totals[(m - 1, vi, opened)])
+            .max()
+        {
+            total = std::cmp::max(total, t);
+        }
+        totals[(m, v, opened)] = total;
+    }
+    totals
+}
+
+params! {
+    pub struct Params {
+        pub minutes1: usize = 30,
+        /// Minutes left once the elephant is taught.
+        pub minutes2: usize = 26,
+    }
+}
+
+#[aoc(day16, part1)]
+pub fn part1(input: &(Vec<Valve>, usize, usize)) -> Option<usize> {
+    part1_with(input, &Params::default())
+}
+
+/// `None` when there is no minute, or too many to solve.
+pub fn part1_with(input: &(Vec<Valve>, usize, usize), params: &Params) -> Option<usize> {
+    let m = params.minutes1;
+    if !fits(input, m) {
+        return None;
+    }
+    Some(get_totals(&input.0, input.1, m)[(m - 1, input.2, (1 << input.1) - 1)] as usize)
+}
+
+#[aoc(day16, part2)]
+pub fn part2(input: &(Vec<Valve>, usize, usize)) -> Option<usize> {
+    part2_with(input, &Params::default())
+}
+
+/// `None` when there is no minute, or too many to solve.
+pub fn part2_with(input: &(Vec<Valve>, usize, usize), params: &Params) -> Option<usize> {
+    let m = params.minutes2;
+    if !fits(input, m) {
+        return None;
+    }
+    let totals = get_totals(&input.0, input.1, m);
+
+    (0..(1 << input.1))
+        .map(|i| {
+            (0..i).fold(0, |mut acc, j| {
+                if i & j == 0 {
+                    acc = std::cmp::max(
+                        acc,
+                        totals[(m - 1, input.2, i)] as usize + totals[(m - 1, input.2, j)] as usize,
+                    );
+                }
+                acc
+            })
+        })
+        .max()
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    type Input = (Vec<Valve>, usize, usize);
+    type Output = Answer;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input_generator(input)
+    }
+    fn part1(input: &Self::Input, params: &Params) -> Self::Output {
+        part1_with(input, params).into()
+    }
+    fn part2(input: &Self::Input, params: &Params) -> Self::Output {
+        part2_with(input, params).into()
+    }
+}
@@ -0,0 +1,264 @@
+use aoc_common::{
+    answer::Answer,
+    grid::Grid,
+    params::params,
+    parse::{ParseError, Parser},
+    simulation::Simulation,
+    solution::Solution,
+    viz::Draw,
+};
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+
+const W: usize = 7;
+const HEIGHT_BUF: usize = 2000;
+/// Rows at the top of the tower shown in a frame.
+const VIEW: usize = 40;
+/// Rows at the top of the tower compared to find a cycle.
+const SURFACE: usize = 64;
+
+lazy_static! {
+    static ref ROCKS: Vec<Rock> = vec![
+        Rock(vec![(0, 0), (1, 0), (2, 0), (3, 0)]),
+        Rock(vec![(1, 0), (0, 1), (1, 1), (1, 2), (2, 1)]),
+        Rock(vec![(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)]),
+        Rock(vec![(0, 0), (0, 1), (0, 2), (0, 3)]),
+        Rock(vec![(0, 0), (0, 1), (1, 0), (1, 1)]),
+    ];
+}
+
+#[derive(Debug, Clone)]
+pub struct Rock(Vec<(usize, usize)>);
+
+impl Rock {
+    pub fn height(&self) -> usize {
+        self.0.iter().map(|&(_, j)| j).max().unwrap() + 1
+    }
+    pub fn width(&self) -> usize {
+        self.0.iter().map(|&(i, _)| i).max().unwrap() + 1
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Direction {
+    Left,
+    Right,
+}
+use Direction::*;
+
+#[aoc_generator(day17)]
+pub fn input_generator(input: &str) -> Result<Game, ParseError> {
+    let p = Parser::new(17, input);
+    let jets = input
+        .char_indices()
+        .map(|(i, c)| match c {
+            '<' => Ok(Left),
+            '>' => Ok(Right),
+            _ => Err(p.error(&input[i..], "`<` or `>`")),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if jets.is_empty() {
+        return Err(p.error(input, "`<` or `>`"));
+    }
+    let map = Grid::new(W, HEIGHT_BUF, false);
+    Ok(Game {
+        jets,
+        map,
+        height: 0,
+        rocks: 0,
+        jet: 0,
+    })
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Game {
+    jets: Vec<Direction>,
+    /// Left out of the dumps, it is empty until the rocks fall.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    map: Grid<bool>,
+    /// Of the tower.
+    height: usize,
+    /// Dropped so far.
+    rocks: usize,
+    /// Index of the next jet to push a rock.
+    jet: usize,
+}
+
+impl Game {
+    pub fn get_cell(&mut self, i: usize, j: usize) -> bool {
+        if let Some(&v) = self.map.get((i, j)) {
+            v
+        } else {
+            let new_len = self.map.height() * 2;
+            self.map.resize(W, new_len, false);
+            false
+        }
+    }
+
+    pub fn can_move_rock(&mut self, rock: usize, x: usize, y: usize) -> bool {
+        ROCKS[rock]
+            .0
+            .iter()
+            .all(|&(i, j)| !self.get_cell(x + i, y + j))
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// How deep the top of each column is below the top of the tower, looking at most
+    /// `SURFACE` rows down.
+    fn surface(&self) -> [usize; W] {
+        let depth = self.height.min(SURFACE);
+        std::array::from_fn(|i| {
+            (0..depth)
+                .find(|&k| self.map[(i, self.height - 1 - k)])
+                .unwrap_or(depth)
+        })
+    }
+
+    /// Height of the tower after `n` rocks. Unless `all` of them are dropped, a cycle is found
+    /// once the next rock, the next jet and the top of the tower repeat, and whole cycles are
+    /// skipped. `None` when the height overflows.
+    pub fn play_tetris(&mut self, n: usize, all: bool) -> Option<usize> {
+        let mut seen = HashMap::new();
+        let mut skipped = None;
+        while self.rocks < n {
+            self.step();
+            if all || skipped.is_some() {
+                continue;
+            }
+            let key = (self.rocks % ROCKS.len(), self.jet, self.surface());
+            if let Some((rocks, height)) = seen.insert(key, (self.rocks, self.height)) {
+                let period = self.rocks - rocks;
+                let cycles = (n - self.rocks) / period;
+                self.rocks += cycles * period;
+                skipped = Some(cycles.checked_mul(self.height - height)?);
+            }
+        }
+        self.height.checked_add(skipped.unwrap_or(0))
+    }
+}
+
+/// One rock dropped per step, the simulation never ends.
+impl Simulation for Game {
+    type State = Game;
+    type Snapshot = Game;
+
+    fn step(&mut self) -> bool {
+        let rock = self.rocks % ROCKS.len();
+        let (mut x, mut y) = (2, self.height + 3);
+
+        loop {
+            let jet = self.jets[self.jet];
+            self.jet = (self.jet + 1) % self.jets.len();
+            match jet {
+                Left if x > 0 && self.can_move_rock(rock, x - 1, y) => x -= 1,
+                Right if x + ROCKS[rock].width() < W && self.can_move_rock(rock, x + 1, y) => {
+                    x += 1
+                }
+                _ => (),
+            }
+            if y == 0 || !self.can_move_rock(rock, x, y - 1) {
+                break;
+            }
+            y -= 1;
+        }
+
+        // yeah just to allocate the vec if needed
+        if self.can_move_rock(rock, x, y) {
+            ROCKS[rock]
+                .0
+                .iter()
+                .for_each(|&(i, j)| self.map[(i + x, j + y)] = true);
+        }
+
+        self.height = std::cmp::max(self.height, y + ROCKS[rock].height());
+        self.rocks += 1;
+        true
+    }
+    fn state(&self) -> &Game {
+        self
+    }
+    fn snapshot(&self) -> Game {
+        self.clone()
+    }
+    fn restore(&mut self, snapshot: Game) {
+        *self = snapshot;
+    }
+}
+
+/// The top of the tower between walls `|`, rocks as `#`, and the floor `-` once it is in view.
+impl Draw for Game {
+    fn draw(&self) -> Grid<char> {
+        let top = self.height + 3;
+        let rows = top.min(VIEW);
+        let floor = top <= VIEW;
+        let mut g = Grid::new(W + 2, rows + floor as usize, '.');
+        for y in 0..rows {
+            let j = top - 1 - y;
+            g[(0, y)] = '|';
+            g[(W + 1, y)] = '|';
+            for i in 0..W {
+                if self.map.get((i, j)) == Some(&true) {
+                    g[(i + 1, y)] = '#';
+                }
+            }
+        }
+        if floor {
+            for i in 0..W + 2 {
+                g[(i, rows)] = if i == 0 || i == W + 1 { '+' } else { '-' };
+            }
+        }
+        g
+    }
+}
+
+params! {
+    pub struct Params {
+        /// Rocks dropped in part 1, one by one.
+        pub rocks1: usize = 2022,
+        /// Rocks dropped in part 2, skipping the repeating cycles.
+        pub rocks2: usize = 1000000000000,
+    }
+}
+
+#[aoc(day17, part1)]
+pub fn part1(input: &Game) -> Option<usize> {
+    part1_with(input, &Params::default())
+}
+
+pub fn part1_with(input: &Game, params: &Params) -> Option<usize> {
+    input.clone().play_tetris(params.rocks1, true)
+}
+
+#[aoc(day17, part2)]
+pub fn part2(input: &Game) -> Option<usize> {
+    part2_with(input, &Params::default())
+}
+
+/// `None` when so many rocks make a tower too high to count.
+pub fn part2_with(input: &Game, params: &Params) -> Option<usize> {
+    input.clone().play_tetris(params.rocks2, false)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    type Input = Game;
+    type Output = Answer;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input_generator(input)
+    }
+    fn part1(input: &Self::Input, params: &Params) -> Self::Output {
+        part1_with(input, params).into()
+    }
+    fn part2(input: &Self::Input, params: &Params) -> Self::Output {
+        part2_with(input, params).into()
+    }
+}
@@ -0,0 +1,103 @@
+use aoc_common::{
+    geom::Point3,
+    parse::{ParseError, Parser},
+    search::{self, Graph},
+    solution::Solution,
+};
+use std::collections::BTreeSet;
+
+pub fn is_in_max(p: &Point3, max: Point3) -> bool {
+    p.x >= -1 && p.x <= max.x + 1 && p.y >= -1 && p.y <= max.y + 1 && p.z >= -1 && p.z <= max.z + 1
+}
+
+/// The air around the droplet, as far as the steam can go.
+struct Steam<'a> {
+    cubes: &'a BTreeSet<Point3>,
+    max: Point3,
+}
+
+impl Graph for Steam<'_> {
+    type Node = Point3;
+
+    fn successors(&self, p: &Point3) -> Vec<(Point3, usize)> {
+        p.neighbours6()
+            .filter(|n| is_in_max(n, self.max) && !self.cubes.contains(n))
+            .map(|n| (n, 1))
+            .collect()
+    }
+    fn is_goal(&self, _: &Point3) -> bool {
+        false
+    }
+}
+
+/// Largest coordinate, keeping the box the steam goes through small.
+pub const MAX_COORD: isize = 99;
+
+/// Sorted, for the dumps to be the same for the same input.
+#[aoc_generator(day18)]
+pub fn input_generator(input: &str) -> Result<BTreeSet<Point3>, ParseError> {
+    let p = Parser::new(18, input);
+    p.lines()
+        .map(|l| {
+            let c = l
+                .split(',')
+                .map(|c| match p.parse::<isize>(c, "a coordinate")? {
+                    n @ 0..=MAX_COORD => Ok(n),
+                    _ => Err(p.error(c, format!("a coordinate between 0 and {}", MAX_COORD))),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            match c[..] {
+                [x, y, z] => Ok(Point3::new(x, y, z)),
+                _ => Err(p.error(l, "a cube like `2,2,2`")),
+            }
+        })
+        .collect()
+}
+
+#[aoc(day18, part1)]
+pub fn part1(input: &BTreeSet<Point3>) -> usize {
+    input
+        .iter()
+        .map(|c| c.neighbours6().filter(|p| !input.contains(p)).count())
+        .sum()
+}
+#[aoc(day18, part2)]
+pub fn part2(input: &BTreeSet<Point3>) -> usize {
+    let max = input.iter().fold(Point3::default(), |mut acc, c| {
+        acc.x = std::cmp::max(acc.x, c.x);
+        acc.y = std::cmp::max(acc.y, c.y);
+        acc.z = std::cmp::max(acc.z, c.z);
+        acc
+    });
+
+    // from a corner outside of the droplet, as no coordinate is negative
+    let steam = search::bfs(&Steam { cubes: input, max }, Point3::new(-1, -1, -1));
+
+    input
+        .iter()
+        .map(|c| {
+            c.neighbours6()
+                .filter(|p| steam.distance(p).is_some())
+                .count()
+        })
+        .sum()
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    type Input = BTreeSet<Point3>;
+    type Output = usize;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input_generator(input)
+    }
+    fn part1(input: &Self::Input, _: &()) -> Self::Output {
+        part1(input)
+    }
+    fn part2(input: &Self::Input, _: &()) -> Self::Output {
+        part2(input)
+    }
+}
@@ -1,6 +1,12 @@
+use aoc_common::{
+    params::params,
+    parse::{ParseError, Parser},
+    solution::Solution,
+};
 use std::collections::{HashSet, VecDeque};
 
 #[derive(Clone, Debug, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Blueprint {
     id: usize,
     ore: usize,
@@ -106,37 +112,71 @@ impl Blueprint {
     }
 }
 
+/// Most expensive robot, the search gets slower with the costs.
+const MAX_COST: usize = 20;
+
 #[aoc_generator(day19)]
-pub fn input_generator(input: &str) -> Vec<Blueprint> {
-    input.lines().map(|l| {
-        let b = scan_fmt!(l, "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. Each obsidian robot costs {} ore and {} clay. Each geode robot costs {} ore and {} obsidian.", usize, usize, usize, usize, usize, usize, usize).unwrap();
-        Blueprint{id: b.0, ore: b.1, clay: b.2, obsidian: (b.3, b.4), geode: (b.5, b.6)}
+pub fn input_generator(input: &str) -> Result<Vec<Blueprint>, ParseError> {
+    let p = Parser::new(19, input);
+    p.lines().map(|l| {
+        let b = scan_fmt!(l, "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. Each obsidian robot costs {} ore and {} clay. Each geode robot costs {} ore and {} obsidian.", usize, usize, usize, usize, usize, usize, usize)
+            .map_err(|_| p.error(l, "`Blueprint N: Each ore robot costs N ore. ...`"))?;
+        if ![b.1, b.2, b.3, b.4, b.5, b.6].iter().all(|c| (1..=MAX_COST).contains(c)) {
+            return Err(p.error(l, format!("costs between 1 and {}", MAX_COST)));
+        }
+        Ok(Blueprint{id: b.0, ore: b.1, clay: b.2, obsidian: (b.3, b.4), geode: (b.5, b.6)})
     }).collect()
 }
 
+params! {
+    pub struct Params {
+        pub minutes1: usize = 24,
+        pub minutes2: usize = 32,
+        /// Blueprints left after the elephants ate the others.
+        pub blueprints2: usize = 3,
+    }
+}
+
 #[aoc(day19, part1)]
 pub fn part1(input: &[Blueprint]) -> usize {
-    input.iter().map(|i| i.run(24) * i.id).sum::<usize>()
+    part1_with(input, &Params::default())
+}
+
+pub fn part1_with(input: &[Blueprint], params: &Params) -> usize {
+    input
+        .iter()
+        .map(|i| i.run(params.minutes1) * i.id)
+        .sum::<usize>()
 }
 
 #[aoc(day19, part2)]
 pub fn part2(input: &[Blueprint]) -> usize {
-    input.iter().take(3).map(|i| i.run(32)).product::<usize>()
+    part2_with(input, &Params::default())
+}
+
+pub fn part2_with(input: &[Blueprint], params: &Params) -> usize {
+    input
+        .iter()
+        .take(params.blueprints2)
+        .map(|i| i.run(params.minutes2))
+        .product::<usize>()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    const INPUT: &str = "Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.
-Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsidian robot costs 3 ore and 8 clay. Each geode robot costs 3 ore and 12 obsidian.";
-    #[test]
-    fn example1() {
-        // too long for now
-        // assert_eq!(part1(&input_generator(INPUT)), 33)
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    type Input = Vec<Blueprint>;
+    type Output = usize;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input_generator(input)
+    }
+    fn part1(input: &Self::Input, params: &Params) -> Self::Output {
+        part1_with(input, params)
     }
-    #[test]
-    fn example2() {
-        // too long for now
-        // assert_eq!(part2(&input_generator(INPUT)), 3472)
+    fn part2(input: &Self::Input, params: &Params) -> Self::Output {
+        part2_with(input, params)
     }
 }
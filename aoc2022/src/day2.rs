@@ -1,4 +1,10 @@
+use aoc_common::{
+    parse::{ParseError, Parser},
+    solution::Solution,
+};
+
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Hand {
     Rock,
     Paper,
@@ -6,6 +12,7 @@ pub enum Hand {
 }
 
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Letter {
     X,
     Y,
@@ -59,25 +66,29 @@ impl Res {
 }
 
 #[aoc_generator(day2)]
-pub fn input_generator(input: &str) -> Vec<(Hand, Letter)> {
-    input
-        .lines()
+pub fn input_generator(input: &str) -> Result<Vec<(Hand, Letter)>, ParseError> {
+    let p = Parser::new(2, input);
+    p.lines()
         .map(|l| {
-            let mut c = l.chars();
-            let one = match c.next() {
-                Some('A') => Rock,
-                Some('B') => Paper,
-                Some('C') => Scissors,
-                _ => unreachable!(),
+            let one = match l.get(..1) {
+                Some("A") => Rock,
+                Some("B") => Paper,
+                Some("C") => Scissors,
+                _ => return Err(p.error(l, "`A`, `B` or `C`")),
             };
-            c.next();
-            let two = match c.next() {
-                Some('X') => X,
-                Some('Y') => Y,
-                Some('Z') => Z,
-                _ => unreachable!(),
+            if l.get(1..2) != Some(" ") {
+                return Err(p.error(Parser::skip(l, 1), "a space"));
+            }
+            let two = match l.get(2..3) {
+                Some("X") => X,
+                Some("Y") => Y,
+                Some("Z") => Z,
+                _ => return Err(p.error(Parser::skip(l, 2), "`X`, `Y` or `Z`")),
             };
-            (one, two)
+            if l.len() > 3 {
+                return Err(p.error(Parser::skip(l, 3), "the end of the line"));
+            }
+            Ok((one, two))
         })
         .collect()
 }
@@ -110,29 +121,22 @@ pub fn part2(input: &[(Hand, Letter)]) -> u64 {
         })
         .sum()
 }
-#[cfg(test)]
-mod tests {
-    use super::*;
-    #[test]
-    fn example1() {
-        assert_eq!(
-            part1(&input_generator(
-                "A Y
-B X
-C Z"
-            )),
-            15
-        )
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    type Input = Vec<(Hand, Letter)>;
+    type Output = u64;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input_generator(input)
+    }
+    fn part1(input: &Self::Input, _: &()) -> Self::Output {
+        part1(input)
     }
-    #[test]
-    fn example2() {
-        assert_eq!(
-            part2(&input_generator(
-                "A Y
-B X
-C Z"
-            )),
-            12
-        )
+    fn part2(input: &Self::Input, _: &()) -> Self::Output {
+        part2(input)
     }
 }
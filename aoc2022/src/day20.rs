@@ -0,0 +1,157 @@
+use aoc_common::{
+    answer::Answer,
+    params::params,
+    parse::{ParseError, Parser},
+    solution::Solution,
+    stream::{Lines, StreamError, Streamed},
+};
+use std::collections::VecDeque;
+use std::io::BufRead;
+
+/// The sum of the grove coordinates, `None` if it overflows.
+pub fn mix(input: &[isize], n: usize) -> Option<isize> {
+    let mut list: VecDeque<(usize, isize)> = input.iter().copied().enumerate().collect();
+
+    for _ in 0..n {
+        for i in 0..input.len() {
+            while list[0].0 != i {
+                list.rotate_left(1);
+            }
+
+            let val = list.pop_front().unwrap();
+            let r = val.1 % (input.len() as isize - 1);
+            if r > 0 {
+                list.rotate_left(r as usize);
+            } else {
+                list.rotate_right(r.unsigned_abs());
+            }
+            list.push_front(val);
+        }
+
+        while list[0].1 != 0 {
+            list.rotate_left(1)
+        }
+    }
+
+    [1000, 2000, 3000]
+        .iter()
+        .try_fold(0isize, |sum, i| sum.checked_add(list[i % list.len()].1))
+}
+
+/// The numbers checked as they are read, then all together at the end.
+struct Numbers {
+    zero: bool,
+    numbers: Vec<isize>,
+}
+
+impl Numbers {
+    fn push(&mut self, p: &Parser, l: &str) -> Result<(), ParseError> {
+        // small enough to be multiplied by the key
+        let n = p.parse::<i32>(l, "a number")? as isize;
+        if n == 0 && std::mem::replace(&mut self.zero, true) {
+            return Err(p.error(l, "a single `0`"));
+        }
+        self.numbers.push(n);
+        Ok(())
+    }
+
+    /// All the numbers, or what is missing at the end of the input.
+    fn finish(self) -> Result<Vec<isize>, &'static str> {
+        match (self.zero, self.numbers.len()) {
+            (false, _) => Err("a `0`"),
+            (_, 1) => Err("a number to move around the `0`"),
+            _ => Ok(self.numbers),
+        }
+    }
+}
+
+#[aoc_generator(day20)]
+pub fn input_generator(input: &str) -> Result<Vec<isize>, ParseError> {
+    let p = Parser::new(20, input);
+    let mut numbers = Numbers {
+        zero: false,
+        numbers: vec![],
+    };
+    for l in p.lines() {
+        numbers.push(&p, l)?;
+    }
+    numbers
+        .finish()
+        .map_err(|expected| p.error(Parser::end(input), expected))
+}
+
+/// The numbers read one line at a time. They all have to be in memory to be mixed, but not the
+/// text of the input.
+pub fn read(input: impl BufRead) -> Result<Vec<isize>, StreamError> {
+    let mut lines = Lines::new(20, input);
+    let mut numbers = Numbers {
+        zero: false,
+        numbers: vec![],
+    };
+    let mut end = Parser::new(20, "").error("", "");
+    while let Some((p, l)) = lines.next_line()? {
+        numbers.push(&p, l)?;
+        end = p.error(Parser::end(l), "");
+    }
+    Ok(numbers.finish().map_err(|expected| ParseError {
+        expected: expected.to_owned(),
+        ..end
+    })?)
+}
+
+params! {
+    pub struct Params {
+        /// Decryption key of part 2.
+        pub key: isize = 811589153,
+        /// Mixing rounds of part 2.
+        pub rounds: usize = 10,
+    }
+}
+
+#[aoc(day20, part1)]
+pub fn part1(input: &[isize]) -> Option<isize> {
+    mix(input, 1)
+}
+pub fn part1_with(input: &[isize], _: &Params) -> Option<isize> {
+    part1(input)
+}
+
+#[aoc(day20, part2)]
+pub fn part2(input: &[isize]) -> Option<isize> {
+    part2_with(input, &Params::default())
+}
+/// `None` when the key is big enough for the numbers or their sum to overflow.
+pub fn part2_with(input: &[isize], params: &Params) -> Option<isize> {
+    let input = input
+        .iter()
+        .map(|i| i.checked_mul(params.key))
+        .collect::<Option<Vec<_>>>()?;
+    mix(&input, params.rounds)
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    type Input = Vec<isize>;
+    type Output = Answer;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input_generator(input)
+    }
+    fn part1(input: &Self::Input, params: &Params) -> Self::Output {
+        part1_with(input, params).into()
+    }
+    fn part2(input: &Self::Input, params: &Params) -> Self::Output {
+        part2_with(input, params).into()
+    }
+    fn stream(input: &mut dyn BufRead, params: &Params) -> Streamed<Self::Output> {
+        Some(read(input).map(|input| {
+            (
+                part1_with(&input, params).into(),
+                part2_with(&input, params).into(),
+            )
+        }))
+    }
+}
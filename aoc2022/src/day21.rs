@@ -0,0 +1,164 @@
+use aoc_common::{
+    answer::Answer,
+    parse::{ParseError, Parser},
+    solution::Solution,
+};
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Op {
+    Shout(isize),
+    Add(String, String),
+    Sub(String, String),
+    Mul(String, String),
+    Div(String, String),
+}
+use Op::*;
+
+/// Most monkeys between `root` and any other, as both parts recurse through them.
+const MAX_DEPTH: usize = 1000;
+
+#[aoc_generator(day21)]
+pub fn input_generator(input: &str) -> Result<HashMap<String, Op>, ParseError> {
+    let p = Parser::new(21, input);
+    let mut operands = Vec::new();
+    let mut waited = HashSet::new();
+    // the two monkeys each monkey waits for, as they are in the input
+    let mut waits = HashMap::new();
+    let hm = p
+        .lines()
+        .map(|l| {
+            let (name, op) = p.split_once(l, ": ", "`NAME: JOB`")?;
+            if let Ok(shout) = op.parse::<isize>() {
+                return match name {
+                    "root" => Err(p.error(op, "`NAME OP NAME` for `root`")),
+                    _ => Ok((name.to_owned(), Shout(shout))),
+                };
+            }
+            let (a, o, b) = match op.split(' ').collect::<Vec<_>>()[..] {
+                [a, o, b] => (a, o, b),
+                _ => return Err(p.error(op, "a number or `NAME OP NAME`")),
+            };
+            // so that the monkeys below `root` form a tree, without any cycle
+            for w in [a, b] {
+                if w == "root" {
+                    return Err(p.error(w, "a monkey other than `root`"));
+                }
+                if !waited.insert(w) {
+                    return Err(p.error(w, "a monkey no other monkey waits for"));
+                }
+            }
+            operands.extend([a, b]);
+            waits.insert(name, [a, b]);
+            let (a, b) = (a.to_string(), b.to_string());
+            match o {
+                "+" => Ok((name.to_owned(), Add(a, b))),
+                "-" => Ok((name.to_owned(), Sub(a, b))),
+                "*" => Ok((name.to_owned(), Mul(a, b))),
+                "/" => Ok((name.to_owned(), Div(a, b))),
+                _ => Err(p.error(o, "`+`, `-`, `*` or `/`")),
+            }
+        })
+        .collect::<Result<HashMap<_, _>, _>>()?;
+    if let Some(o) = operands.into_iter().find(|&o| !hm.contains_key(o)) {
+        return Err(p.error(o, "a known monkey"));
+    }
+    for name in ["root", "humn"] {
+        if !hm.contains_key(name) {
+            return Err(p.error(Parser::end(input), format!("a `{}` monkey", name)));
+        }
+    }
+    let mut below = vec![("root", 0)];
+    while let Some((name, depth)) = below.pop() {
+        for &w in waits.get(name).into_iter().flatten() {
+            if depth == MAX_DEPTH {
+                return Err(p.error(w, "fewer monkeys between `root` and this one"));
+            }
+            below.push((w, depth + 1));
+        }
+    }
+    Ok(hm)
+}
+
+/// What `start` yells, `None` if a number overflows or is divided by zero.
+pub fn walk(hm: &HashMap<String, Op>, start: &str) -> Option<isize> {
+    match &hm[start] {
+        Shout(n) => Some(*n),
+        Add(a, b) => walk(hm, a)?.checked_add(walk(hm, b)?),
+        Sub(a, b) => walk(hm, a)?.checked_sub(walk(hm, b)?),
+        Mul(a, b) => walk(hm, a)?.checked_mul(walk(hm, b)?),
+        Div(a, b) => walk(hm, a)?.checked_div(walk(hm, b)?),
+    }
+}
+
+pub fn contains(hm: &HashMap<String, Op>, start: &str, until: &str) -> bool {
+    start == until
+        || match &hm[start] {
+            Shout(_) => false,
+            Add(a, b) | Div(a, b) | Mul(a, b) | Sub(a, b) => {
+                contains(hm, a, until) || contains(hm, b, until)
+            }
+        }
+}
+
+/// `a / b` when `b` divides `a`, as only whole numbers are yelled.
+fn exact_div(a: isize, b: isize) -> Option<isize> {
+    (a.checked_rem(b)? == 0).then(|| a / b)
+}
+
+/// What `x` has to yell for `start` to yell `val`, `None` if `x` is not below `start`, a
+/// number overflows or is divided by zero on the way, or no whole number does.
+pub fn find(hm: &HashMap<String, Op>, start: &str, val: isize, x: &str) -> Option<isize> {
+    if start == x {
+        return Some(val);
+    }
+    match &hm[start] {
+        Add(a, b) if contains(hm, a, x) => find(hm, a, val.checked_sub(walk(hm, b)?)?, x),
+        Add(a, b) if contains(hm, b, x) => find(hm, b, val.checked_sub(walk(hm, a)?)?, x),
+        Sub(a, b) if contains(hm, a, x) => find(hm, a, val.checked_add(walk(hm, b)?)?, x),
+        Sub(a, b) if contains(hm, b, x) => find(hm, b, walk(hm, a)?.checked_sub(val)?, x),
+        Mul(a, b) if contains(hm, a, x) => find(hm, a, exact_div(val, walk(hm, b)?)?, x),
+        Mul(a, b) if contains(hm, b, x) => find(hm, b, exact_div(val, walk(hm, a)?)?, x),
+        Div(a, b) if contains(hm, a, x) => find(hm, a, val.checked_mul(walk(hm, b)?)?, x),
+        Div(a, b) if contains(hm, b, x) => find(hm, b, exact_div(walk(hm, a)?, val)?, x),
+        _ => None,
+    }
+}
+
+#[aoc(day21, part1)]
+pub fn part1(input: &HashMap<String, Op>) -> Option<isize> {
+    walk(input, "root")
+}
+
+/// Whatever its operation, `root` checks that both its monkeys yell the same number.
+#[aoc(day21, part2)]
+pub fn part2(input: &HashMap<String, Op>) -> Option<isize> {
+    let (Add(s0, s1) | Sub(s0, s1) | Mul(s0, s1) | Div(s0, s1)) = &input["root"] else {
+        return None;
+    };
+    if contains(input, s0, "humn") {
+        find(input, s0, walk(input, s1)?, "humn")
+    } else {
+        find(input, s1, walk(input, s0)?, "humn")
+    }
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    type Input = HashMap<String, Op>;
+    type Output = Answer;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input_generator(input)
+    }
+    fn part1(input: &Self::Input, _: &()) -> Self::Output {
+        part1(input).into()
+    }
+    fn part2(input: &Self::Input, _: &()) -> Self::Output {
+        part2(input).into()
+    }
+}
@@ -0,0 +1,353 @@
+use aoc_common::{
+    geom::Dir4,
+    grid::Grid,
+    parse::{ParseError, Parser},
+    solution::Solution,
+    viz::FrameSink,
+};
+use std::{
+    collections::{HashMap, HashSet},
+    io,
+};
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    static ref REG: Regex = Regex::new(r"((\d+|[LR])+?)").unwrap();
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Inst {
+    Right,
+    Left,
+    Move(usize),
+}
+use Inst::*;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Pos(usize, usize, Dir4);
+
+impl Pos {
+    pub fn left(&self) -> Pos {
+        Pos(self.0, self.1, self.2.turn_left())
+    }
+    pub fn right(&self) -> Pos {
+        Pos(self.0, self.1, self.2.turn_right())
+    }
+    pub fn score(&self) -> usize {
+        1000 * (self.1 + 1) + 4 * (self.0 + 1) + self.2 as usize
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Turn {
+    Left,
+    Right,
+    NoTurn,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Walker {
+    pub(crate) map: Grid<char>,
+    pub(crate) inst: Vec<Inst>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "aoc_common::dump::sorted_pairs")
+    )]
+    cube_transitions: HashMap<Pos, Pos>,
+}
+
+impl Walker {
+    pub fn get(&self, p: Pos) -> char {
+        self.map[(p.0, p.1)]
+    }
+
+    pub fn gen_cube_transitions(&mut self, mut pos: Pos) {
+        let start = pos;
+        let mut fold = false;
+        let mut unknwon_turns = vec![Turn::Right];
+        let mut unknwon_pos = Vec::new();
+        let (w, h) = (self.map.width(), self.map.height());
+        let size = num::integer::gcd(h, w);
+        self.map.resize(w + 1, h + 1, ' ');
+
+        loop {
+            // let's walk until the next corner
+            for _ in 0..size {
+                if !fold {
+                    unknwon_pos.push(pos);
+                    pos = self.walk_one(pos);
+                } else {
+                    // let's fold it by adding the transitions
+                    let p = unknwon_pos.pop().unwrap();
+                    self.cube_transitions.insert(p.left(), pos.right());
+                    self.cube_transitions.insert(pos.left(), p.right());
+                    pos = self.walk_one(pos);
+                }
+            }
+
+            let turn = match (self.get(pos), self.get(self.walk_one(pos.left()))) {
+                // if there is no face on the left, and we are not on a face, it's a right turn
+                (' ', ' ') => Turn::Right,
+                // if we currently are walking on a face, and there is othing on the left, it's a
+                // straight line
+                (c, ' ') if c != ' ' => Turn::NoTurn,
+                // else it's a left turn
+                _ => Turn::Left,
+            };
+
+            if !fold {
+                if turn == Turn::Left {
+                    // always fold on the left
+                    fold = true;
+                } else {
+                    unknwon_turns.push(turn);
+                }
+            } else {
+                if let Some(last_turn) = unknwon_turns.pop() {
+                    // two right after after fold (so a left) can be sum'd up to a line,
+                    // ie no turn and we can stop folding
+                    if last_turn == Turn::Right && turn == Turn::Right {
+                        unknwon_turns.push(Turn::NoTurn);
+                        fold = false;
+                    }
+                }
+                // if we folded everything, we can wait for a new left turn
+                if unknwon_turns.is_empty() {
+                    unknwon_turns.push(turn);
+                    fold = false;
+                }
+            }
+            // let's get back on the right position
+            match turn {
+                Turn::Left => pos = self.walk_one(pos.left()),
+                Turn::Right => pos = self.walk_one(pos.right().right()).left(),
+                Turn::NoTurn => (),
+            }
+            // every border pos have a transition
+            if pos == start {
+                break;
+            }
+        }
+    }
+
+    pub fn walk_cube(&self, pos: Pos) -> Pos {
+        self.cube_transitions
+            .get(&pos)
+            .copied()
+            .unwrap_or(self.walk_one(pos))
+    }
+
+    pub fn walk_one(&self, mut pos: Pos) -> Pos {
+        match pos.2 {
+            Dir4::Left => pos.0 = (pos.0.overflowing_sub(1).0).min(self.map.width() - 1),
+            Dir4::Right => pos.0 = (pos.0 + 1) % self.map.width(),
+            Dir4::Up => pos.1 = (pos.1.overflowing_sub(1).0).min(self.map.height() - 1),
+            Dir4::Down => pos.1 = (pos.1 + 1) % self.map.height(),
+        };
+        pos
+    }
+
+    pub fn walk_flat(&self, mut pos: Pos) -> Pos {
+        loop {
+            pos = self.walk_one(pos);
+
+            if self.get(pos) != ' ' {
+                return pos;
+            }
+        }
+    }
+
+    pub fn walk(&mut self, cube: bool) -> usize {
+        self.path(cube).last().unwrap().score()
+    }
+
+    /// Every position the walk goes through, a turn giving a new position.
+    pub fn path(&mut self, cube: bool) -> Vec<Pos> {
+        let mut pos = Pos(
+            self.map.row(0).position(|&c| c != ' ').unwrap(),
+            0,
+            Dir4::Right,
+        );
+
+        if cube {
+            self.gen_cube_transitions(pos);
+        }
+
+        let mut path = vec![pos];
+        for i in self.inst.iter().copied() {
+            match i {
+                Move(n) => {
+                    for _ in 0..n {
+                        let p = if !cube {
+                            self.walk_flat(pos)
+                        } else {
+                            self.walk_cube(pos)
+                        };
+                        if self.get(p) == '#' {
+                            break;
+                        }
+                        pos = p;
+                        path.push(pos);
+                    }
+                }
+                Right => {
+                    pos = pos.right();
+                    path.push(pos)
+                }
+                Left => {
+                    pos = pos.left();
+                    path.push(pos)
+                }
+            };
+        }
+        path
+    }
+
+    /// Sends the map with the start, then a frame per position of the path drawn as the arrow
+    /// of its facing, for at most `max_steps` positions. Returns the number of positions drawn
+    /// after the start.
+    pub fn record(
+        &mut self,
+        cube: bool,
+        sink: &mut dyn FrameSink,
+        max_steps: usize,
+    ) -> io::Result<usize> {
+        let path = self.path(cube);
+        let arrow = |d| match d {
+            Dir4::Right => '>',
+            Dir4::Down => 'v',
+            Dir4::Left => '<',
+            Dir4::Up => '^',
+        };
+        let mut frame = self.map.clone();
+        frame[(path[0].0, path[0].1)] = arrow(path[0].2);
+        sink.frame(&frame)?;
+        let steps = (path.len() - 1).min(max_steps);
+        for p in &path[1..=steps] {
+            frame[(p.0, p.1)] = arrow(p.2);
+            sink.frame(&frame)?;
+        }
+        Ok(steps)
+    }
+}
+
+/// Longest move, walked one tile at a time.
+const MAX_MOVE: usize = 10000;
+
+/// Whether the map is made of six square faces which fold into a cube: rolling a die over them
+/// from face to face puts each side of the die on the table once.
+fn is_cube_net(map: &Grid<char>) -> bool {
+    let tiles = map.iter().filter(|(_, &c)| c != ' ').count();
+    let Some(s) = (1..=tiles).find(|s| 6 * s * s >= tiles) else {
+        return false;
+    };
+    let is_face = |(x, y): (usize, usize)| map.get((x * s, y * s)).is_some_and(|&c| c != ' ');
+    if 6 * s * s != tiles
+        || map
+            .iter()
+            .any(|((x, y), &c)| (c != ' ') != is_face((x / s, y / s)))
+    {
+        return false;
+    }
+
+    // sides of the die: on the table, on top, then towards the right, left, down and up
+    let first: (usize, usize) = (map.row(0).position(|&c| c != ' ').unwrap_or(0) / s, 0);
+    let mut die = HashMap::from([(first, [0, 1, 2, 3, 4, 5])]);
+    let mut queue = vec![first];
+    while let Some((x, y)) = queue.pop() {
+        let [table, top, right, left, down, up] = die[&(x, y)];
+        let rolled = [
+            (Some((x + 1, y)), [right, left, top, table, down, up]),
+            (
+                x.checked_sub(1).map(|x| (x, y)),
+                [left, right, table, top, down, up],
+            ),
+            (Some((x, y + 1)), [down, up, right, left, top, table]),
+            (
+                y.checked_sub(1).map(|y| (x, y)),
+                [up, down, right, left, table, top],
+            ),
+        ];
+        for (b, sides) in rolled {
+            let Some(b) = b else {
+                continue;
+            };
+            if is_face(b) && !die.contains_key(&b) {
+                die.insert(b, sides);
+                queue.push(b);
+            }
+        }
+    }
+    let on_table = die.values().map(|sides| sides[0]).collect::<HashSet<_>>();
+    die.len() == 6 && on_table.len() == 6
+}
+
+#[aoc_generator(day22)]
+pub fn input_generator(input: &str) -> Result<Walker, ParseError> {
+    let p = Parser::new(22, input);
+    let (input_map, inst_input) = p.split_once(input, "\n\n", "an empty line after the map")?;
+    let map = Grid::parse_padded(&p, input_map, ' ', "` `, `.` or `#`", |c| {
+        matches!(c, ' ' | '.' | '#').then_some(c)
+    })?;
+
+    if !map.row(0).any(|&c| c != ' ') {
+        return Err(p.error(input, "a tile on the first row"));
+    }
+    if !is_cube_net(&map) {
+        return Err(p.error(input_map, "the net of a cube"));
+    }
+
+    if let Some(i) = inst_input.find(|c: char| !matches!(c, 'L' | 'R' | '0'..='9')) {
+        return Err(p.error(&inst_input[i..], "a number of tiles, `L` or `R`"));
+    }
+    let inst = REG
+        .find_iter(inst_input)
+        .map(|m| match m.as_str() {
+            "R" => Ok(Right),
+            "L" => Ok(Left),
+            n => match p.parse::<usize>(n, "a number of tiles")? {
+                tiles if tiles > MAX_MOVE => Err(p.error(n, format!("at most {} tiles", MAX_MOVE))),
+                tiles => Ok(Move(tiles)),
+            },
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(Walker {
+        map,
+        inst,
+        cube_transitions: HashMap::new(),
+    })
+}
+
+#[aoc(day22, part1)]
+pub fn part1(input: &Walker) -> usize {
+    input.clone().walk(false)
+}
+
+#[aoc(day22, part2)]
+pub fn part2(input: &Walker) -> usize {
+    input.clone().walk(true)
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+    type Input = Walker;
+    type Output = usize;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input_generator(input)
+    }
+    fn part1(input: &Self::Input, _: &()) -> Self::Output {
+        part1(input)
+    }
+    fn part2(input: &Self::Input, _: &()) -> Self::Output {
+        part2(input)
+    }
+}
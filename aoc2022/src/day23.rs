@@ -0,0 +1,187 @@
+use aoc_common::{
+    geom::Dir8::{self, *},
+    grid::{Grid, Pos},
+    parse::{ParseError, Parser},
+    simulation::Simulation,
+    solution::Solution,
+    viz::Draw,
+};
+use std::collections::{HashMap, VecDeque};
+
+use lazy_static::lazy_static;
+
+type Move = ([Dir8; 3], Dir8);
+
+lazy_static! {
+    static ref MOVES: VecDeque<Move> = {
+        let mut m = VecDeque::new();
+        m.push_back(([UpLeft, Up, UpRight], Up));
+        m.push_back(([DownLeft, Down, DownRight], Down));
+        m.push_back(([Left, DownLeft, UpLeft], Left));
+        m.push_back(([Right, DownRight, UpRight], Right));
+        m
+    };
+}
+
+/// Empty cells added around the elves whenever one of them reaches the edge of the grid.
+const MARGIN: usize = 10;
+
+#[aoc_generator(day23)]
+pub fn input_generator(input: &str) -> Result<Grid<bool>, ParseError> {
+    let p = Parser::new(23, input);
+    let grid = Grid::parse(&p, input, "`#` or `.`", |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+    if !grid.positions().any(|p| grid[p]) {
+        return Err(p.error(Parser::end(input), "an elf"));
+    }
+    Ok(grid)
+}
+
+pub fn has_elf<I>(input: &Grid<bool>, pos: Pos, mut dirs: I) -> bool
+where
+    I: Iterator<Item = Dir8>,
+{
+    dirs.any(|d| input.offset(pos, d.delta()).is_some_and(|p| input[p]))
+}
+
+fn elves(input: &Grid<bool>) -> Vec<Pos> {
+    input.positions().filter(|&p| input[p]).collect()
+}
+
+/// The elves spreading out, one round per step.
+#[derive(Clone)]
+pub struct Elves {
+    grid: Grid<bool>,
+    moves: VecDeque<Move>,
+}
+
+impl Elves {
+    pub fn new(grid: Grid<bool>) -> Self {
+        Elves {
+            grid,
+            moves: MOVES.clone(),
+        }
+    }
+
+    /// Empty tiles in the smallest rectangle containing every elf.
+    pub fn empty_ground(&self) -> usize {
+        let elves = elves(&self.grid);
+        let max_x = elves.iter().map(|p| p.0).max().unwrap();
+        let max_y = elves.iter().map(|p| p.1).max().unwrap();
+        let min_y = elves.iter().map(|p| p.1).min().unwrap();
+        let min_x = elves.iter().map(|p| p.0).min().unwrap();
+
+        (max_x - min_x + 1) * (max_y - min_y + 1) - elves.len()
+    }
+}
+
+impl Simulation for Elves {
+    type State = Grid<bool>;
+    type Snapshot = Elves;
+
+    /// Over once no elf moves.
+    fn step(&mut self) -> bool {
+        let (w, h) = (self.grid.width(), self.grid.height());
+        let mut elves = elves(&self.grid);
+        // kept only if an elf moves, so that the last step changes nothing
+        let padded = elves
+            .iter()
+            .any(|&(x, y)| x == 0 || y == 0 || x == w - 1 || y == h - 1)
+            .then(|| self.grid.padded(MARGIN, false));
+        if let Some(padded) = &padded {
+            elves = self::elves(padded);
+        }
+        let input = padded.as_ref().unwrap_or(&self.grid);
+
+        let mut proposed: HashMap<Pos, Pos> = HashMap::new();
+        for &elf in elves.iter() {
+            if !has_elf(input, elf, Dir8::ALL.into_iter()) {
+                _ = proposed.insert(elf, elf);
+                continue;
+            }
+
+            let len = proposed.len();
+            for m in self.moves.iter() {
+                if !has_elf(input, elf, m.0.iter().copied()) {
+                    let pos = input.offset(elf, m.1.delta()).unwrap();
+                    if proposed.contains_key(&pos) {
+                        let old = proposed.remove(&pos).unwrap();
+                        proposed.insert(old, old);
+                        proposed.insert(elf, elf);
+                    } else {
+                        _ = proposed.insert(pos, elf)
+                    }
+                    break;
+                }
+            }
+            if len == proposed.len() {
+                _ = proposed.insert(elf, elf);
+            }
+        }
+        let mut new_input = Grid::new(input.width(), input.height(), false);
+        proposed.keys().for_each(|&p| new_input[p] = true);
+        if *input == new_input {
+            return false;
+        }
+        self.grid = new_input;
+        self.moves.rotate_left(1);
+        true
+    }
+    fn state(&self) -> &Grid<bool> {
+        &self.grid
+    }
+    fn snapshot(&self) -> Elves {
+        self.clone()
+    }
+    fn restore(&mut self, snapshot: Elves) {
+        *self = snapshot;
+    }
+}
+
+/// Elves as `#`.
+impl Draw for Elves {
+    fn draw(&self) -> Grid<char> {
+        self.grid.map(|&e| if e { '#' } else { '.' })
+    }
+}
+
+pub fn run(input: Grid<bool>, n: isize) -> isize {
+    let mut elves = Elves::new(input);
+    if n < 0 {
+        return elves.run_until(|_| false) as isize + 1;
+    }
+    // the ground is the same after the elves stop
+    elves.run(n as usize);
+    elves.empty_ground() as isize
+}
+
+#[aoc(day23, part1)]
+pub fn part1(input: &Grid<bool>) -> isize {
+    run(input.clone(), 10)
+}
+#[aoc(day23, part2)]
+pub fn part2(input: &Grid<bool>) -> isize {
+    run(input.clone(), -1)
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    type Input = Grid<bool>;
+    type Output = isize;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input_generator(input)
+    }
+    fn part1(input: &Self::Input, _: &()) -> Self::Output {
+        part1(input)
+    }
+    fn part2(input: &Self::Input, _: &()) -> Self::Output {
+        part2(input)
+    }
+}
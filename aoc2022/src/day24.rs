@@ -0,0 +1,241 @@
+use aoc_common::{
+    answer::Answer,
+    geom::Dir4,
+    grid::{Grid, Pos},
+    parse::{ParseError, Parser},
+    search::{self, Graph},
+    simulation::Simulation,
+    solution::Solution,
+    viz::Draw,
+};
+
+use std::cell::RefCell;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Tile {
+    Empty,
+    Wall,
+    Blizzards(Vec<Dir4>),
+}
+use Tile::*;
+
+impl Tile {
+    pub fn add(&mut self, b: Dir4) {
+        match self {
+            Wall => unreachable!(),
+            Blizzards(bs) => bs.push(b),
+            Empty => *self = Blizzards(vec![b]),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Map {
+    tiles: Grid<Tile>,
+    pos: Pos,
+    end: Pos,
+}
+
+#[aoc_generator(day24)]
+pub fn input_generator(input: &str) -> Result<Map, ParseError> {
+    let p = Parser::new(24, input);
+    let tiles = Grid::parse(&p, input, "`#`, `.`, `>`, `<`, `^` or `v`", |c| match c {
+        '#' => Some(Wall),
+        '.' => Some(Empty),
+        '>' => Some(Blizzards(vec![Dir4::Right])),
+        '<' => Some(Blizzards(vec![Dir4::Left])),
+        '^' => Some(Blizzards(vec![Dir4::Up])),
+        'v' => Some(Blizzards(vec![Dir4::Down])),
+        _ => None,
+    })?;
+    let (w, h) = (tiles.width(), tiles.height());
+    if w < 3 || h < 3 {
+        return Err(p.error(input, "a valley inside walls"));
+    }
+    let opening = |j: usize| {
+        (j < h)
+            .then(|| tiles.row(j).position(|t| t == &Empty))
+            .flatten()
+            .ok_or_else(|| p.error(p.lines().nth(j).unwrap_or_default(), "an opening"))
+    };
+    let pos = (opening(0)?, 0);
+    let end = (opening(h.saturating_sub(1))?, h.saturating_sub(1));
+
+    // the blizzards wrap around inside the walls, and never go into the openings
+    let at = |(x, y): Pos| Parser::skip(p.lines().nth(y).unwrap_or_default(), x);
+    for (q, t) in tiles.iter() {
+        let border = q.0 == 0 || q.1 == 0 || q.0 == w - 1 || q.1 == h - 1;
+        let expected = match t {
+            Wall if !border => "`.` or a blizzard inside the walls",
+            Empty if border && ((q != pos && q != end) || q.0 == 0 || q.0 == w - 1) => "`#`",
+            Blizzards(_) if border => "`#`",
+            Blizzards(bs) if (q.0 == pos.0 || q.0 == end.0) && bs[0].delta().x == 0 => {
+                "no `^` or `v` in the columns of the openings"
+            }
+            _ => continue,
+        };
+        return Err(p.error(at(q), expected));
+    }
+    Ok(Map { tiles, pos, end })
+}
+
+impl Map {
+    pub fn tiles_cleaned(&self) -> Grid<Tile> {
+        self.tiles.map(|t| match t {
+            Blizzards(_) | Empty => Empty,
+            Wall => Wall,
+        })
+    }
+
+    pub fn get_blizzard_next_pos(&self, pos: Pos, b: Dir4) -> Pos {
+        let mut pos = self.tiles.offset(pos, b.delta()).unwrap();
+        if self.tiles[pos] == Wall {
+            match b {
+                Dir4::Left => pos.0 = self.tiles.width() - 2,
+                Dir4::Right => pos.0 = 1,
+                Dir4::Up => pos.1 = self.tiles.height() - 2,
+                Dir4::Down => pos.1 = 1,
+            }
+        };
+        pos
+    }
+
+    pub fn move_blizzards(&mut self) {
+        let mut new_tiles = self.tiles_cleaned();
+        for j in 1..self.tiles.height() - 1 {
+            for i in 1..self.tiles.width() - 1 {
+                match &self.tiles[(i, j)] {
+                    Empty => (),
+                    Wall => unreachable!(),
+                    Blizzards(bs) => {
+                        for &b in bs {
+                            let new_pos = self.get_blizzard_next_pos((i, j), b);
+                            new_tiles[new_pos].add(b);
+                        }
+                    }
+                }
+            }
+        }
+        self.tiles = new_tiles;
+    }
+}
+
+/// The blizzards moving, one minute per step, forever.
+impl Simulation for Map {
+    type State = Grid<Tile>;
+    type Snapshot = Grid<Tile>;
+
+    fn step(&mut self) -> bool {
+        self.move_blizzards();
+        true
+    }
+    fn state(&self) -> &Grid<Tile> {
+        &self.tiles
+    }
+    fn snapshot(&self) -> Grid<Tile> {
+        self.tiles.clone()
+    }
+    fn restore(&mut self, snapshot: Grid<Tile>) {
+        self.tiles = snapshot;
+    }
+}
+
+/// Walls as `#`, lone blizzards as their arrow and several ones as their number.
+impl Draw for Map {
+    fn draw(&self) -> Grid<char> {
+        self.tiles.map(|t| match t {
+            Empty => '.',
+            Wall => '#',
+            Blizzards(bs) if bs.len() == 1 => match bs[0] {
+                Dir4::Right => '>',
+                Dir4::Down => 'v',
+                Dir4::Left => '<',
+                Dir4::Up => '^',
+            },
+            Blizzards(bs) => char::from_digit(bs.len().min(9) as u32, 10).unwrap(),
+        })
+    }
+}
+
+/// Positions in the valley over time, the blizzards being computed as they are needed. They
+/// are back where they started after `period` minutes, so the time is kept modulo the period.
+struct Valley {
+    maps: RefCell<Vec<Map>>,
+    period: usize,
+    end: Pos,
+}
+
+impl Graph for Valley {
+    type Node = (Pos, usize);
+
+    fn successors(&self, &(pos, t): &(Pos, usize)) -> Vec<((Pos, usize), usize)> {
+        let mut maps = self.maps.borrow_mut();
+        let next = (t + 1) % self.period;
+        if maps.len() == next {
+            let mut new_map = maps[t].clone();
+            new_map.step();
+            maps.push(new_map);
+        }
+        let current_map = &maps[next];
+
+        current_map
+            .tiles
+            .neighbours4(pos)
+            .chain([pos])
+            .filter(|&p| current_map.tiles[p] == Empty)
+            .map(|p| ((p, next), 1))
+            .collect()
+    }
+    fn is_goal(&self, &(pos, _): &(Pos, usize)) -> bool {
+        pos == self.end
+    }
+}
+
+/// The map once at the end, and the time it took, unless the end cannot be reached.
+pub fn bfs(map: Map) -> Option<(Map, usize)> {
+    let (w, h) = (map.tiles.width(), map.tiles.height());
+    let valley = Valley {
+        end: map.end,
+        period: num::integer::lcm(w - 2, h - 2),
+        maps: RefCell::new(vec![map.clone()]),
+    };
+    let search = search::bfs(&valley, (map.pos, 0));
+    let (_, t) = search.goal?;
+    let d = search.goal_distance()?;
+    Some((valley.maps.into_inner().swap_remove(t), d))
+}
+
+#[aoc(day24, part1)]
+pub fn part1(input: &Map) -> Option<usize> {
+    bfs(input.clone()).map(|(_, d)| d)
+}
+#[aoc(day24, part2)]
+pub fn part2(input: &Map) -> Option<usize> {
+    let (mut map, d1) = bfs(input.clone())?;
+    std::mem::swap(&mut map.end, &mut map.pos);
+    let (mut map, d2) = bfs(map)?;
+    std::mem::swap(&mut map.end, &mut map.pos);
+    let (_, d3) = bfs(map)?;
+    Some(d1 + d2 + d3)
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+    type Input = Map;
+    type Output = Answer;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input_generator(input)
+    }
+    fn part1(input: &Self::Input, _: &()) -> Self::Output {
+        part1(input).into()
+    }
+    fn part2(input: &Self::Input, _: &()) -> Self::Output {
+        part2(input).into()
+    }
+}
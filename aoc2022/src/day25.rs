@@ -0,0 +1,97 @@
+use aoc_common::{
+    answer::Answer,
+    parse::{ParseError, Parser},
+    solution::Solution,
+};
+
+pub fn to_snafu(n: usize) -> String {
+    let mut tmp = n;
+    let mut res = String::new();
+    while tmp != 0 {
+        let (mut d, r) = (tmp / 5, tmp % 5);
+        match r {
+            3 => {
+                res.push('=');
+                d += 1
+            }
+            4 => {
+                res.push('-');
+                d += 1
+            }
+            n => res.push_str(&n.to_string()),
+        }
+        tmp = d;
+    }
+    res.chars().rev().collect()
+}
+
+/// Longest number, so that it fits in an `isize` whatever its digits.
+const MAX_DIGITS: usize = 26;
+
+fn value(s: &str) -> isize {
+    s.bytes().fold(0, |res, c| {
+        let n = match c {
+            b'0' => 0,
+            b'1' => 1,
+            b'2' => 2,
+            b'-' => -1,
+            b'=' => -2,
+            _ => unreachable!(),
+        };
+        res * 5 + n
+    })
+}
+
+pub fn from_snafu(s: &str) -> usize {
+    value(s) as usize
+}
+
+#[aoc_generator(day25)]
+pub fn input_generator(input: &str) -> Result<Vec<String>, ParseError> {
+    let p = Parser::new(25, input);
+    let mut total = 0_usize;
+    p.lines()
+        .map(|l| {
+            if let Some(i) = l.find(|c| !matches!(c, '0' | '1' | '2' | '-' | '=')) {
+                return Err(p.error(&l[i..], "a SNAFU digit"));
+            }
+            if l.len() > MAX_DIGITS {
+                let expected = format!("at most {} digits", MAX_DIGITS);
+                return Err(p.error(&l[MAX_DIGITS..], expected));
+            }
+            let n = usize::try_from(value(l)).map_err(|_| p.error(l, "a positive number"))?;
+            total = total
+                .checked_add(n)
+                .ok_or_else(|| p.error(l, "numbers with a smaller sum"))?;
+            Ok(l.to_string())
+        })
+        .collect()
+}
+#[aoc(day25, part1)]
+pub fn part1(input: &[String]) -> String {
+    to_snafu(input.iter().map(String::as_str).map(from_snafu).sum())
+}
+/// There is no puzzle for the last star.
+#[aoc(day25, part2)]
+pub fn part2(_: &[String]) -> Answer {
+    Answer::Nothing
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    type Input = Vec<String>;
+    type Output = Answer;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input_generator(input)
+    }
+    fn part1(input: &Self::Input, _: &()) -> Self::Output {
+        part1(input).into()
+    }
+    fn part2(input: &Self::Input, _: &()) -> Self::Output {
+        part2(input)
+    }
+}
@@ -0,0 +1,96 @@
+use aoc_common::{
+    parse::{ParseError, Parser},
+    solution::Solution,
+};
+use itertools::Itertools;
+use std::collections::{BTreeSet, HashSet};
+
+/// Sorted, for the dumps to be the same for the same input.
+pub type Rucksack = (BTreeSet<char>, BTreeSet<char>);
+
+#[aoc_generator(day3)]
+pub fn input_generator(input: &str) -> Result<Vec<Rucksack>, ParseError> {
+    let p = Parser::new(3, input);
+    let rucksacks = p
+        .lines()
+        .map(|l| {
+            if let Some(i) = l.find(|c: char| !c.is_ascii_alphabetic()) {
+                return Err(p.error(&l[i..], "an item letter"));
+            }
+            if l.len() % 2 == 1 {
+                return Err(p.error(Parser::end(l), "as many items in both compartments"));
+            }
+            let (s1, s2) = l.split_at(l.len() / 2);
+            let r: Rucksack = (s1.chars().collect(), s2.chars().collect());
+            if r.0.is_disjoint(&r.1) {
+                return Err(p.error(l, "an item in both compartments"));
+            }
+            Ok(r)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let lines = p.lines().collect::<Vec<_>>();
+    for (group, r) in lines.chunks(3).zip(rucksacks.chunks(3)) {
+        if group.len() < 3 {
+            return Err(p.error(Parser::end(input), "groups of three rucksacks"));
+        }
+        let items = r.iter().map(|(a, b)| a | b).collect::<Vec<_>>();
+        if !items[0]
+            .iter()
+            .any(|c| items[1].contains(c) && items[2].contains(c))
+        {
+            return Err(p.error(group[0], "a badge common to the group"));
+        }
+    }
+    Ok(rucksacks)
+}
+
+pub fn get_score(c: &char) -> u32 {
+    if c.is_lowercase() {
+        *c as u32 - 96
+    } else {
+        *c as u32 - 38
+    }
+}
+
+#[aoc(day3, part1)]
+pub fn part1(input: &[Rucksack]) -> u32 {
+    input.iter().fold(0, |acc, i| {
+        acc + get_score(i.0.intersection(&i.1).next().unwrap())
+    })
+}
+
+#[aoc(day3, part2)]
+pub fn part2(input: &[Rucksack]) -> u32 {
+    input
+        .iter()
+        .map(|(a, b)| a.union(b).collect::<HashSet<_>>())
+        .chunks(3)
+        .into_iter()
+        .map(|c| {
+            c.reduce(|acc, r| acc.intersection(&r).cloned().collect::<HashSet<_>>())
+                .unwrap()
+                .into_iter()
+                .next()
+                .unwrap()
+        })
+        .fold(0, |acc, c| acc + get_score(c))
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    type Input = Vec<Rucksack>;
+    type Output = u32;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input_generator(input)
+    }
+    fn part1(input: &Self::Input, _: &()) -> Self::Output {
+        part1(input)
+    }
+    fn part2(input: &Self::Input, _: &()) -> Self::Output {
+        part2(input)
+    }
+}
@@ -0,0 +1,64 @@
+use aoc_common::{
+    parse::{ParseError, Parser},
+    solution::Solution,
+};
+
+pub type Pair = ((usize, usize), (usize, usize));
+
+fn is_contained(p: &Pair) -> bool {
+    p.0 .0 >= p.1 .0 && p.0 .1 <= p.1 .1 || p.1 .0 >= p.0 .0 && p.1 .1 <= p.0 .1
+}
+
+fn overlap(p: &Pair) -> bool {
+    p.0 .1 >= p.1 .0 && p.0 .0 <= p.1 .1 || p.0 .1 <= p.1 .0 && p.0 .0 >= p.1 .1
+}
+
+#[aoc_generator(day4)]
+pub fn input_generator(input: &str) -> Result<Vec<Pair>, ParseError> {
+    let p = Parser::new(4, input);
+    let range = |r: &str| {
+        let (x, y) = p.split_once(r, "-", "a range like `2-4`")?;
+        let start = p.parse(x, "a section")?;
+        match p.parse(y, "a section")? {
+            end if end < start => Err(p.error(y, "a section not before the start of the range")),
+            end => Ok((start, end)),
+        }
+    };
+    p.lines()
+        .map(|l| {
+            let (a, b) = p.split_once(l, ",", "`,` between the two ranges")?;
+            Ok((range(a)?, range(b)?))
+        })
+        .collect()
+}
+#[aoc(day4, part1)]
+pub fn part1(input: &[Pair]) -> usize {
+    input
+        .iter()
+        .fold(0, |acc, p| if is_contained(p) { acc + 1 } else { acc })
+}
+#[aoc(day4, part2)]
+pub fn part2(input: &[Pair]) -> usize {
+    input
+        .iter()
+        .fold(0, |acc, p| if overlap(p) { acc + 1 } else { acc })
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    type Input = Vec<Pair>;
+    type Output = usize;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input_generator(input)
+    }
+    fn part1(input: &Self::Input, _: &()) -> Self::Output {
+        part1(input)
+    }
+    fn part2(input: &Self::Input, _: &()) -> Self::Output {
+        part2(input)
+    }
+}
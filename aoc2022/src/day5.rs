@@ -0,0 +1,104 @@
+use aoc_common::{
+    normalise::Trim,
+    parse::{ParseError, Parser},
+    solution::Solution,
+};
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Game {
+    crates: Vec<Vec<char>>,
+    inst: Vec<(usize, usize, usize)>,
+}
+
+#[aoc_generator(day5)]
+pub fn input_generator(input: &str) -> Result<Game, ParseError> {
+    let p = Parser::new(5, input);
+    let (stacks, insts) = p.split_once(input, "\n\n", "an empty line after the stacks")?;
+    let n = (stacks.lines().next().unwrap_or_default().len() + 1) / 4;
+    let mut crates = vec![Vec::new(); n];
+    for l in stacks.lines().rev().skip(1) {
+        for (i, (j, c)) in l.char_indices().skip(1).step_by(4).enumerate() {
+            if c != ' ' {
+                crates
+                    .get_mut(i)
+                    .ok_or_else(|| p.error(&l[j..], format!("at most {} stacks", n)))?
+                    .push(c);
+            }
+        }
+    }
+
+    // the moves are the same in both parts, only the order of the crates differs
+    let mut sizes = crates.iter().map(Vec::len).collect::<Vec<_>>();
+    let inst = insts
+        .lines()
+        .map(|l| {
+            let inst = scan_fmt!(l, "move {d} from {d} to {d}", usize, usize, usize)
+                .map_err(|_| p.error(l, "`move N from A to B`"))?;
+            if !(1..=n).contains(&inst.1) || !(1..=n).contains(&inst.2) {
+                return Err(p.error(l, format!("a stack between 1 and {}", n)));
+            }
+            if sizes[inst.1 - 1] < inst.0 {
+                let left = sizes[inst.1 - 1];
+                return Err(p.error(l, format!("at most {} crates to move", left)));
+            }
+            sizes[inst.1 - 1] -= inst.0;
+            sizes[inst.2 - 1] += inst.0;
+            Ok(inst)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(Game { crates, inst })
+}
+
+impl Game {
+    pub fn run_p1(&mut self) -> String {
+        self.inst.iter().for_each(|inst| {
+            for _ in 0..inst.0 {
+                let tmp = self.crates[inst.1 - 1].pop().unwrap();
+                self.crates[inst.2 - 1].push(tmp);
+            }
+        });
+        self.crates.iter().filter_map(|c| c.last()).collect()
+    }
+    pub fn run_p2(&mut self) -> String {
+        self.inst.iter().for_each(|inst| {
+            let mut group = vec![' '; inst.0];
+            for g in group.iter_mut() {
+                *g = self.crates[inst.1 - 1].pop().unwrap();
+            }
+            group.reverse();
+            self.crates[inst.2 - 1].append(&mut group);
+        });
+        self.crates.iter().filter_map(|c| c.last()).collect()
+    }
+}
+#[aoc(day5, part1)]
+pub fn part1(input: &Game) -> String {
+    input.clone().run_p1()
+}
+
+#[aoc(day5, part2)]
+pub fn part2(input: &Game) -> String {
+    input.clone().run_p2()
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    type Input = Game;
+    type Output = String;
+    type Params = ();
+    // the first line of the drawing gives the number of stacks, with its trailing spaces
+    const TRIM: Trim = Trim::End;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input_generator(input)
+    }
+    fn part1(input: &Self::Input, _: &()) -> Self::Output {
+        part1(input)
+    }
+    fn part2(input: &Self::Input, _: &()) -> Self::Output {
+        part2(input)
+    }
+}
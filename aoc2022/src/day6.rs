@@ -0,0 +1,109 @@
+use aoc_common::{
+    answer::Answer,
+    parse::{ParseError, Parser},
+    solution::Solution,
+    stream::{skip_bom, StreamError, Streamed},
+};
+use itertools::FoldWhile::{Continue, Done};
+use itertools::Itertools;
+use std::collections::{HashSet, VecDeque};
+use std::io::BufRead;
+
+/// `None` without a marker, when no `n` letters in a row are all different.
+fn solve(input: &[char], n: usize) -> Option<usize> {
+    let found = input.windows(n).fold_while(0, |acc, cs| {
+        let hs: HashSet<&char> = cs.iter().collect();
+        if hs.len() == n {
+            return Done(acc);
+        }
+        Continue(acc + 1)
+    });
+    match found {
+        Done(acc) => Some(acc + n),
+        Continue(_) => None,
+    }
+}
+
+#[aoc_generator(day6)]
+pub fn input_generator(input: &str) -> Result<Vec<char>, ParseError> {
+    let p = Parser::new(6, input);
+    if let Some(i) = input.find(|c: char| !c.is_ascii_lowercase()) {
+        return Err(p.error(&input[i..], "a lowercase letter"));
+    }
+    Ok(input.chars().collect())
+}
+#[aoc(day6, part1)]
+pub fn part1(input: &[char]) -> Option<usize> {
+    solve(input, 4)
+}
+#[aoc(day6, part2)]
+pub fn part2(input: &[char]) -> Option<usize> {
+    solve(input, 14)
+}
+
+/// Both markers, remembering only the last 14 letters.
+pub fn stream(mut input: impl BufRead) -> Result<(Option<usize>, Option<usize>), StreamError> {
+    let sizes = [4, 14];
+    let mut last = VecDeque::with_capacity(14);
+    let mut markers = [None; 2];
+    let (mut len, mut space) = (0, false);
+    skip_bom(&mut input)?;
+    for b in input.bytes() {
+        let b = b?;
+        if b.is_ascii_whitespace() {
+            space = true;
+            continue;
+        }
+        // only whitespace can come after the letters
+        if space || !b.is_ascii_lowercase() {
+            return Err(ParseError {
+                day: 6,
+                line: 1,
+                column: len + 1,
+                expected: "a lowercase letter".to_owned(),
+            }
+            .into());
+        }
+        len += 1;
+        if last.len() == 14 {
+            last.pop_front();
+        }
+        last.push_back(b);
+        for (marker, &n) in markers.iter_mut().zip(&sizes) {
+            let distinct = |cs: &VecDeque<u8>| {
+                let set = cs
+                    .iter()
+                    .rev()
+                    .take(n)
+                    .fold(0_u32, |s, c| s | 1 << (c - b'a'));
+                set.count_ones() as usize == n
+            };
+            if marker.is_none() && last.len() >= n && distinct(&last) {
+                *marker = Some(len);
+            }
+        }
+    }
+    Ok((markers[0], markers[1]))
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    type Input = Vec<char>;
+    type Output = Answer;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input_generator(input)
+    }
+    fn part1(input: &Self::Input, _: &()) -> Self::Output {
+        part1(input).into()
+    }
+    fn part2(input: &Self::Input, _: &()) -> Self::Output {
+        part2(input).into()
+    }
+    fn stream(input: &mut dyn BufRead, _: &()) -> Streamed<Self::Output> {
+        Some(stream(input).map(|(part1, part2)| (part1.into(), part2.into())))
+    }
+}
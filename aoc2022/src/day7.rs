@@ -0,0 +1,123 @@
+use aoc_common::{
+    answer::Answer,
+    params::params,
+    parse::{ParseError, Parser},
+    solution::Solution,
+};
+use std::collections::{HashMap, HashSet};
+
+type Output = HashMap<String, usize>;
+
+#[aoc_generator(day7)]
+pub fn input_generator(input: &str) -> Result<Output, ParseError> {
+    let p = Parser::new(7, input);
+    let mut hm = HashMap::new();
+    let mut current_dir = Vec::new();
+    for l in input.split('$').skip(1).map(|l| l.trim()) {
+        match l.lines().next().unwrap_or(l) {
+            "cd /" => {
+                current_dir = vec![""];
+            }
+            "cd .." => {
+                current_dir.pop();
+            }
+            c if c.starts_with("cd ") => {
+                current_dir.push(&c[3..]);
+            }
+            "ls" => {
+                let entries = l
+                    .lines()
+                    .skip(1)
+                    .map(|output| {
+                        let (s, n) = p.split_once(output, " ", "`dir NAME` or `SIZE NAME`")?;
+                        match s {
+                            "dir" => Ok((n, None)),
+                            s => Ok((n, Some(p.parse::<usize>(s, "`dir` or a file size")?))),
+                        }
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                hm.entry(current_dir.join("/"))
+                    .or_insert((0, HashSet::new()))
+                    .1
+                    .extend(entries);
+            }
+            c => return Err(p.error(c, "`cd DIR` or `ls`")),
+        }
+    }
+
+    let mut keys = hm.keys().cloned().collect::<Vec<_>>();
+    keys.sort_by_key(|dir| usize::MAX - dir.matches("/").count());
+    keys.iter()
+        .map(|k| {
+            let size = hm[k].1.iter().try_fold(0usize, |total, (n, s)| {
+                let s = match s {
+                    None => hm
+                        .get(&format!("{}/{}", k, n))
+                        .map(|d| d.0)
+                        .ok_or_else(|| p.error(n, "a later `ls` of this directory"))?,
+                    Some(s) => *s,
+                };
+                total
+                    .checked_add(s)
+                    .ok_or_else(|| p.error(n, "smaller file sizes"))
+            })?;
+            hm.get_mut(k).unwrap().0 = size;
+            Ok((k.clone(), size))
+        })
+        .collect::<Result<Output, _>>()
+        .and_then(|sizes| match sizes.contains_key("") {
+            true => Ok(sizes),
+            false => Err(p.error(Parser::end(input), "an `ls` of `/`")),
+        })
+}
+
+params! {
+    pub struct Params {
+        /// Biggest size of the directories summed in part 1.
+        pub small_dir: usize = 100000,
+        pub disk_size: usize = 70000000,
+        /// Free space needed on the disk in part 2.
+        pub update_size: usize = 30000000,
+    }
+}
+
+#[aoc(day7, part1)]
+pub fn part1(input: &Output) -> usize {
+    part1_with(input, &Params::default())
+}
+
+#[aoc(day7, part2)]
+pub fn part2(input: &Output) -> Option<usize> {
+    part2_with(input, &Params::default())
+}
+
+pub fn part1_with(input: &Output, params: &Params) -> usize {
+    input.values().filter(|&&s| s <= params.small_dir).sum()
+}
+
+/// `None` when even deleting `/` does not free enough space, with an update bigger than the disk.
+pub fn part2_with(input: &Output, params: &Params) -> Option<usize> {
+    let needed = input[""]
+        .saturating_add(params.update_size)
+        .saturating_sub(params.disk_size);
+    input.values().filter(|&&s| s >= needed).min().copied()
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    type Input = HashMap<String, usize>;
+    type Output = Answer;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input_generator(input)
+    }
+    fn part1(input: &Self::Input, params: &Params) -> Self::Output {
+        part1_with(input, params).into()
+    }
+    fn part2(input: &Self::Input, params: &Params) -> Self::Output {
+        part2_with(input, params).into()
+    }
+}
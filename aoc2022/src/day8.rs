@@ -0,0 +1,119 @@
+use aoc_common::{
+    geom::Dir4,
+    grid::{Grid, Pos},
+    parse::{ParseError, Parser},
+    solution::{Solution, Variant},
+};
+use itertools::FoldWhile::{Continue, Done};
+use itertools::Itertools;
+
+#[aoc_generator(day8)]
+pub fn input_generator(input: &str) -> Result<Grid<u8>, ParseError> {
+    let p = Parser::new(8, input);
+    Grid::parse(&p, input, "a tree height", |c| {
+        c.to_digit(10).map(|d| d as u8)
+    })
+}
+
+pub fn is_visible(m: &Grid<u8>, pos: Pos) -> bool {
+    let h = m[pos];
+    Dir4::ALL
+        .iter()
+        .any(|d| m.ray(pos, d.delta()).all(|p| m[p] < h))
+}
+
+pub fn score(m: &Grid<u8>, pos: Pos) -> usize {
+    let h = m[pos];
+    Dir4::ALL.iter().fold(1, |acc, d| {
+        acc * m
+            .ray(pos, d.delta())
+            .fold_while(0, |acc2, p| {
+                if m[p] < h {
+                    Continue(acc2 + 1)
+                } else {
+                    Done(acc2 + 1)
+                }
+            })
+            .into_inner()
+    })
+}
+
+#[aoc(day8, part1)]
+pub fn part1(input: &Grid<u8>) -> usize {
+    input.positions().filter(|&p| is_visible(input, p)).count()
+}
+#[aoc(day8, part2)]
+pub fn part2(input: &Grid<u8>) -> usize {
+    input.positions().map(|p| score(input, p)).max().unwrap()
+}
+
+/// Number of `trees` seen by a tree of height `h`, up to the first one as high included.
+fn seen<'a>(trees: impl Iterator<Item = &'a u8>, h: u8) -> usize {
+    let mut n = 0;
+    for &t in trees {
+        n += 1;
+        if t >= h {
+            break;
+        }
+    }
+    n
+}
+
+/// Same as [`is_visible`], following the rows and columns of the grid instead of rays.
+pub fn is_visible_fast(m: &Grid<u8>, (x, y): Pos) -> bool {
+    let (w, h, t) = (m.width(), m.height(), m[(x, y)]);
+    m.column(x).rev().skip(h - y).all(|&u| u < t)
+        || m.column(x).skip(y + 1).all(|&u| u < t)
+        || m.row(y).rev().skip(w - x).all(|&u| u < t)
+        || m.row(y).skip(x + 1).all(|&u| u < t)
+}
+
+/// Same as [`score`], following the rows and columns of the grid instead of rays.
+pub fn score_fast(m: &Grid<u8>, (x, y): Pos) -> usize {
+    let (w, h, t) = (m.width(), m.height(), m[(x, y)]);
+    seen(m.column(x).rev().skip(h - y), t)
+        * seen(m.column(x).skip(y + 1), t)
+        * seen(m.row(y).rev().skip(w - x), t)
+        * seen(m.row(y).skip(x + 1), t)
+}
+
+#[aoc(day8, part1, fast)]
+pub fn part1_fast(input: &Grid<u8>) -> usize {
+    input
+        .positions()
+        .filter(|&p| is_visible_fast(input, p))
+        .count()
+}
+#[aoc(day8, part2, fast)]
+pub fn part2_fast(input: &Grid<u8>) -> usize {
+    input
+        .positions()
+        .map(|p| score_fast(input, p))
+        .max()
+        .unwrap()
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    type Input = Grid<u8>;
+    type Output = usize;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input_generator(input)
+    }
+    fn part1(input: &Self::Input, _: &()) -> Self::Output {
+        part1(input)
+    }
+    fn part2(input: &Self::Input, _: &()) -> Self::Output {
+        part2(input)
+    }
+    fn variants() -> Vec<Variant<Self>> {
+        vec![
+            Variant::new(1, "fast", |input, _| part1_fast(input)),
+            Variant::new(2, "fast", |input, _| part2_fast(input)),
+        ]
+    }
+}
@@ -0,0 +1,195 @@
+use aoc_common::{
+    geom::{Dir4, Point2},
+    grid::Grid,
+    parse::{ParseError, Parser},
+    simulation::Simulation,
+    solution::Solution,
+    stream::{Lines, StreamError, Streamed},
+    viz::Draw,
+};
+use std::collections::HashSet;
+use std::io::BufRead;
+
+/// Longest move, the rope takes them one step at a time.
+const MAX_STEPS: usize = 10000;
+
+fn parse_move(p: &Parser, l: &str) -> Result<(Dir4, usize), ParseError> {
+    let (c, s) = p.split_once(l, " ", "`DIRECTION STEPS`")?;
+    let c = match c {
+        "R" => Dir4::Right,
+        "L" => Dir4::Left,
+        "U" => Dir4::Up,
+        "D" => Dir4::Down,
+        _ => return Err(p.error(c, "`R`, `L`, `U` or `D`")),
+    };
+    match p.parse(s, "a number of steps")? {
+        n if n > MAX_STEPS => Err(p.error(s, format!("at most {} steps", MAX_STEPS))),
+        n => Ok((c, n)),
+    }
+}
+
+#[aoc_generator(day9)]
+pub fn input_generator(input: &str) -> Result<Vec<(Dir4, usize)>, ParseError> {
+    let p = Parser::new(9, input);
+    p.lines().map(|l| parse_move(&p, l)).collect()
+}
+
+pub fn move_tails(tails: &mut [Point2]) {
+    let mut h = tails.first().cloned().unwrap();
+    tails.iter_mut().skip(1).for_each(|t| {
+        if t.chebyshev(h) > 1 {
+            *t += (h - *t).signum();
+        }
+        h = *t;
+    })
+}
+
+/// The rope following the moves of its head, one knot length per step.
+#[derive(Clone)]
+pub struct Rope<'a> {
+    moves: &'a [(Dir4, usize)],
+    /// Next move, and how far along it the head already is.
+    next: (usize, usize),
+    knots: Vec<Point2>,
+    visited: HashSet<Point2>,
+    /// Corners of the area covered by the head, which the tail never leaves.
+    bounds: (Point2, Point2),
+}
+
+impl<'a> Rope<'a> {
+    pub fn new(moves: &'a [(Dir4, usize)], tail_size: usize) -> Self {
+        let mut head = Point2::default();
+        let mut bounds = (head, head);
+        for &(d, n) in moves {
+            head += d.delta() * n as isize;
+            bounds.0 = Point2::new(bounds.0.x.min(head.x), bounds.0.y.min(head.y));
+            bounds.1 = Point2::new(bounds.1.x.max(head.x), bounds.1.y.max(head.y));
+        }
+        Rope {
+            moves,
+            next: (0, 0),
+            knots: vec![Point2::default(); tail_size + 1],
+            visited: HashSet::from([Point2::default()]),
+            bounds,
+        }
+    }
+
+    /// Positions the last knot has been at so far.
+    pub fn visited(&self) -> &HashSet<Point2> {
+        &self.visited
+    }
+}
+
+impl Simulation for Rope<'_> {
+    /// Head first.
+    type State = [Point2];
+    type Snapshot = Self;
+
+    fn step(&mut self) -> bool {
+        while self.next.0 < self.moves.len() && self.next.1 == self.moves[self.next.0].1 {
+            self.next = (self.next.0 + 1, 0);
+        }
+        let Some(&(d, _)) = self.moves.get(self.next.0) else {
+            return false;
+        };
+        self.next.1 += 1;
+        self.knots[0] += d.delta();
+        move_tails(&mut self.knots);
+        self.visited.insert(*self.knots.last().unwrap());
+        true
+    }
+    fn state(&self) -> &[Point2] {
+        &self.knots
+    }
+    fn snapshot(&self) -> Self {
+        self.clone()
+    }
+    fn restore(&mut self, snapshot: Self) {
+        *self = snapshot;
+    }
+}
+
+/// Visited cells as `#`, the start as `s`, the head as `H` and the other knots as their number
+/// (or `T` for a single tail).
+impl Draw for Rope<'_> {
+    fn draw(&self) -> Grid<char> {
+        let (min, max) = self.bounds;
+        let cell = |p: Point2| ((p.x - min.x) as usize, (p.y - min.y) as usize);
+        let mut g = Grid::new(
+            (max.x - min.x + 1) as usize,
+            (max.y - min.y + 1) as usize,
+            '.',
+        );
+        for &p in &self.visited {
+            g[cell(p)] = '#';
+        }
+        g[cell(Point2::default())] = 's';
+        for (i, &k) in self.knots.iter().enumerate().rev() {
+            g[cell(k)] = match i {
+                0 => 'H',
+                1 if self.knots.len() == 2 => 'T',
+                i => char::from_digit(i as u32, 36).unwrap(),
+            };
+        }
+        g
+    }
+}
+
+pub fn run(input: &[(Dir4, usize)], tail_size: usize) -> usize {
+    let mut rope = Rope::new(input, tail_size);
+    rope.run_until(|_| false);
+    rope.visited().len()
+}
+
+#[aoc(day9, part1)]
+pub fn part1(input: &[(Dir4, usize)]) -> usize {
+    run(input, 1)
+}
+
+#[aoc(day9, part2)]
+pub fn part2(input: &[(Dir4, usize)]) -> usize {
+    run(input, 9)
+}
+
+/// Both parts with a single rope of 10 knots, whose second knot is the tail of part 1, moved as
+/// the moves are read. Only the visited positions are kept.
+pub fn stream(input: impl BufRead) -> Result<(usize, usize), StreamError> {
+    let mut lines = Lines::new(9, input);
+    let mut knots = [Point2::default(); 10];
+    let mut visited = [
+        HashSet::from([Point2::default()]),
+        HashSet::from([Point2::default()]),
+    ];
+    while let Some((p, l)) = lines.next_line()? {
+        let (d, n) = parse_move(&p, l)?;
+        for _ in 0..n {
+            knots[0] += d.delta();
+            move_tails(&mut knots);
+            visited[0].insert(knots[1]);
+            visited[1].insert(knots[9]);
+        }
+    }
+    Ok((visited[0].len(), visited[1].len()))
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    type Input = Vec<(Dir4, usize)>;
+    type Output = usize;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input_generator(input)
+    }
+    fn part1(input: &Self::Input, _: &()) -> Self::Output {
+        part1(input)
+    }
+    fn part2(input: &Self::Input, _: &()) -> Self::Output {
+        part2(input)
+    }
+    fn stream(input: &mut dyn BufRead, _: &()) -> Streamed<Self::Output> {
+        Some(stream(input))
+    }
+}
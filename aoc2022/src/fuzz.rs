@@ -0,0 +1,121 @@
+//! What the fuzz targets in `fuzz/` run, kept here so the tests can run it too.
+
+use crate::solution::registry;
+use aoc_common::params::Params;
+
+/// Feeds `data` to the parser of `day`, then to both parts if it was accepted. Whatever the
+/// bytes, this must return quickly, without panicking.
+pub fn run(day: u8, data: &[u8]) {
+    let mut params = registry()[&day].params();
+    // the default 24 and 32 minutes take seconds per blueprint, 10 take milliseconds
+    if day == 19 {
+        for minutes in ["minutes1", "minutes2"] {
+            params.set(minutes, "10").unwrap();
+        }
+    }
+    run_with(day, data, params.as_ref())
+}
+
+/// Same as [`run`], with other parameters than the default ones.
+pub fn run_with(day: u8, data: &[u8], params: &dyn Params) {
+    let Ok(input) = std::str::from_utf8(data) else {
+        return;
+    };
+    let solver = &registry()[&day];
+    if let Ok(parsed) = solver.parse(input) {
+        solver.part1(parsed.as_ref(), params);
+        solver.part2(parsed.as_ref(), params);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gen::{generate, Rng};
+    use std::panic;
+
+    /// A few small edits of a valid input, the kind a fuzzer starts with.
+    fn mutate(rng: &mut Rng, input: &str) -> String {
+        let mut chars: Vec<char> = input.chars().collect();
+        let mut lines: Vec<&str> = input.lines().collect();
+        let pos = rng.below(chars.len() + 1);
+        match rng.below(7) {
+            0 => chars.truncate(pos),
+            1 if !lines.is_empty() => {
+                lines.remove(rng.below(lines.len()));
+                return lines.join("\n");
+            }
+            2 if !lines.is_empty() => {
+                let i = rng.below(lines.len());
+                lines.insert(i, lines[i]);
+                return lines.join("\n");
+            }
+            3 if pos < chars.len() => chars[pos] = *rng.pick(&chars.clone()),
+            4 if pos < chars.len() => chars[pos] = *rng.pick(&['0', '9', ' ', '\n', '-', ',']),
+            5 => chars
+                .splice(pos..pos, "99999999999999999999".chars())
+                .for_each(drop),
+            _ => chars.splice(pos..pos, "0".chars()).for_each(drop),
+        }
+        chars.into_iter().collect()
+    }
+
+    /// Inputs which made a day panic or never return.
+    #[test]
+    fn found() {
+        // more than 64 valves, with rates small enough or too big for the totals
+        let valves = |rate: usize| -> String {
+            (0..70)
+                .map(|i| {
+                    let name = format!("{}{}", (b'A' + i / 26) as char, (b'A' + i % 26) as char);
+                    let rate = if i < 2 { rate } else { 0 };
+                    format!(
+                        "Valve {} has flow rate={}; tunnel leads to valve AA\n",
+                        name, rate
+                    )
+                })
+                .collect()
+        };
+        let (small, big) = (valves(1), valves(60000));
+        let monkey = |items: &str, op: &str, div: usize| {
+            format!(
+                "Monkey 0:\n  Starting items: {}\n  Operation: new = old {}\n  Test: divisible by {}\n    If true: throw to monkey 1\n    If false: throw to monkey 1\n\nMonkey 1:\n  Starting items: 1\n  Operation: new = old + 1\n  Test: divisible by 1\n    If true: throw to monkey 0\n    If false: throw to monkey 0",
+                items, op, div
+            )
+        };
+        let (squared, divided) = (
+            monkey("4294967295", "* old", 65536),
+            monkey("1", "+ 1", 1 << 40),
+        );
+        for (day, input) in [
+            (11, squared.as_str()),
+            (11, divided.as_str()),
+            (11, "Monkey 0:\n  Starting items: 1\n  Operation: new = old + 1\n  Test: divisible by 1\n    If true: throw to monkey 0\n    If false: throw to monkey 0"),
+            (16, small.as_str()),
+            (16, big.as_str()),
+            (21, "root: a - humn\na: 5\nhumn: 1"),
+            (21, "root: a + humn\na: b / c\nb: 1\nc: 0\nhumn: 1"),
+            (21, "root: a * humn\na: 9223372036854775807\nhumn: 2"),
+            (21, "root: a + b\na: humn * c\nc: 2\nb: 3\nhumn: 1"),
+        ] {
+            run(day, input.as_bytes());
+        }
+    }
+
+    #[test]
+    fn mutations() {
+        for day in 1..=25 {
+            let mut rng = Rng::new(day as u64);
+            let valid = generate(day, 0, 0).unwrap();
+            for _ in 0..20 {
+                let mut input = valid.clone();
+                for _ in 0..=rng.below(3) {
+                    input = mutate(&mut rng, &input);
+                }
+                if panic::catch_unwind(|| run(day, input.as_bytes())).is_err() {
+                    panic!("day {} panicked on:\n{}", day, input);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,780 @@
+//! Random valid puzzle inputs, bigger than the real ones for benchmarks and as small as wanted
+//! for tests. The same day, seed and size always give the same input.
+
+use std::collections::HashSet;
+
+use crate::day18;
+use crate::day25::to_snafu;
+
+/// SplitMix64, small and stable so that a seed gives the same input whatever the platform or
+/// the versions of the dependencies.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// In `0..n`, `n` must not be 0.
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// In `lo..=hi`.
+    pub fn between(&mut self, lo: i64, hi: i64) -> i64 {
+        lo + (self.next_u64() % (hi - lo + 1) as u64) as i64
+    }
+
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn pick<'a, T>(&mut self, s: &'a [T]) -> &'a T {
+        &s[self.below(s.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, s: &mut [T]) {
+        for i in (1..s.len()).rev() {
+            s.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// Input of a day, `size` scaling it from tiny (0) to much bigger than the real inputs.
+/// Without trailing newline, like the inputs given to the generators.
+pub fn generate(day: u8, seed: u64, size: usize) -> Option<String> {
+    let rng = &mut Rng::new(seed ^ ((day as u64) << 56));
+    Some(match day {
+        1 => day1(rng, size),
+        2 => day2(rng, size),
+        3 => day3(rng, size),
+        4 => day4(rng, size),
+        5 => day5(rng, size),
+        6 => day6(rng, size),
+        7 => day7(rng, size),
+        8 => day8(rng, size),
+        9 => day9(rng, size),
+        10 => day10(rng),
+        11 => day11(rng, size),
+        12 => day12(rng, size),
+        13 => day13(rng, size),
+        14 => day14(rng, size),
+        15 => day15(rng, size),
+        16 => day16(rng, size),
+        17 => day17(rng, size),
+        18 => day18(rng, size),
+        19 => day19(rng, size),
+        20 => day20(rng, size),
+        21 => day21(rng, size),
+        22 => day22(rng, size),
+        23 => day23(rng, size),
+        24 => day24(rng, size),
+        25 => day25(rng, size),
+        _ => return None,
+    })
+}
+
+fn lines(l: impl IntoIterator<Item = String>) -> String {
+    l.into_iter().collect::<Vec<_>>().join("\n")
+}
+
+const LETTERS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Unique names made of `len` letters of `alphabet`, avoiding the reserved ones.
+struct Names {
+    used: HashSet<String>,
+    alphabet: &'static [u8],
+    len: usize,
+}
+
+impl Names {
+    fn new(alphabet: &'static [u8], len: usize, reserved: &[&str]) -> Self {
+        Names {
+            used: reserved.iter().map(|s| s.to_string()).collect(),
+            alphabet,
+            len,
+        }
+    }
+
+    fn next(&mut self, rng: &mut Rng) -> String {
+        loop {
+            let name: String = (0..self.len)
+                .map(|_| *rng.pick(self.alphabet) as char)
+                .collect();
+            if self.used.insert(name.clone()) {
+                return name;
+            }
+        }
+    }
+}
+
+/// Groups of calories.
+fn day1(rng: &mut Rng, size: usize) -> String {
+    let groups = (0..3 + size * 100)
+        .map(|_| lines((0..rng.between(1, 12)).map(|_| rng.between(1000, 60000).to_string())));
+    groups.collect::<Vec<_>>().join("\n\n")
+}
+
+fn day2(rng: &mut Rng, size: usize) -> String {
+    lines((0..1 + size * 1000).map(|_| {
+        format!(
+            "{} {}",
+            *rng.pick(b"ABC") as char,
+            *rng.pick(b"XYZ") as char
+        )
+    }))
+}
+
+/// Each rucksack has one item in both compartments, each group of three one badge.
+fn day3(rng: &mut Rng, size: usize) -> String {
+    let mut sacks = Vec::new();
+    for _ in 0..1 + size * 100 {
+        let badge = *rng.pick(LETTERS);
+        // any other item is missing from at least one rucksack of the group
+        let missing_from: Vec<usize> = LETTERS.iter().map(|_| rng.below(3)).collect();
+        for r in 0..3 {
+            let mut allowed: Vec<u8> = LETTERS
+                .iter()
+                .zip(&missing_from)
+                .filter(|&(&c, &m)| c != badge && m != r)
+                .map(|(&c, _)| c)
+                .collect();
+            rng.shuffle(&mut allowed);
+            let common = if rng.chance(0.2) {
+                badge
+            } else {
+                allowed.pop().unwrap()
+            };
+            let (left, right) = allowed.split_at(allowed.len() / 2);
+            let len = rng.between(4, 16) as usize;
+            let mut halves = [vec![common], vec![common]];
+            if common != badge {
+                halves[rng.below(2)].push(badge);
+            }
+            for (half, items) in halves.iter_mut().zip([left, right]) {
+                while half.len() < len {
+                    half.push(*rng.pick(items));
+                }
+                rng.shuffle(half);
+            }
+            sacks.push(halves.concat().into_iter().map(char::from).collect());
+        }
+    }
+    lines(sacks)
+}
+
+fn day4(rng: &mut Rng, size: usize) -> String {
+    let mut range = || {
+        let a = rng.between(1, 99);
+        (a, rng.between(a, 99))
+    };
+    lines((0..1 + size * 1000).map(|_| {
+        let ((a, b), (c, d)) = (range(), range());
+        format!("{}-{},{}-{}", a, b, c, d)
+    }))
+}
+
+/// The moves never empty a stack.
+fn day5(rng: &mut Rng, size: usize) -> String {
+    let n = rng.between(3, 9) as usize;
+    let mut heights: Vec<usize> = (0..n)
+        .map(|_| rng.between(1, 8 + size as i64) as usize)
+        .collect();
+    heights[0] = heights[0].max(2);
+    let top = *heights.iter().max().unwrap();
+    let mut drawing: Vec<String> = (0..top)
+        .rev()
+        .map(|level| {
+            let row = heights.iter().map(|&h| match h > level {
+                true => format!("[{}]", *rng.pick(&LETTERS[26..]) as char),
+                false => "   ".to_owned(),
+            });
+            row.collect::<Vec<_>>().join(" ")
+        })
+        .collect();
+    drawing.push(
+        (1..=n)
+            .map(|i| format!(" {} ", i))
+            .collect::<Vec<_>>()
+            .join(" "),
+    );
+    let moves = (0..1 + size * 100).map(|_| {
+        let from = loop {
+            let i = rng.below(n);
+            if heights[i] > 1 {
+                break i;
+            }
+        };
+        let to = (from + 1 + rng.below(n - 1)) % n;
+        let count = rng.between(1, heights[from] as i64 - 1) as usize;
+        heights[from] -= count;
+        heights[to] += count;
+        format!("move {} from {} to {}", count, from + 1, to + 1)
+    });
+    let moves = lines(moves);
+    format!("{}\n\n{}", lines(drawing), moves)
+}
+
+/// Ends with fourteen different characters, so that both markers exist.
+fn day6(rng: &mut Rng, size: usize) -> String {
+    let mut s: Vec<u8> = (0..size * 4000)
+        .map(|_| *rng.pick(&LETTERS[..26]))
+        .collect();
+    let mut marker = LETTERS[..26].to_vec();
+    rng.shuffle(&mut marker);
+    s.extend(&marker[..14]);
+    String::from_utf8(s).unwrap()
+}
+
+/// A random tree of directories explored depth first.
+fn day7(rng: &mut Rng, size: usize) -> String {
+    fn explore(rng: &mut Rng, out: &mut Vec<String>, dirs: &mut usize, depth: usize) {
+        out.push("$ ls".to_owned());
+        let mut names = Names::new(&LETTERS[..26], 3, &[]);
+        let mut subdirs = Vec::new();
+        for _ in 0..rng.between(1, 6) {
+            let name = names.next(rng);
+            if *dirs > 0 && depth < 8 && rng.chance(0.4) {
+                *dirs -= 1;
+                out.push(format!("dir {}", name));
+                subdirs.push(name);
+            } else if rng.chance(0.5) {
+                out.push(format!(
+                    "{} {}.{}",
+                    rng.between(1, 300000),
+                    name,
+                    names.next(rng)
+                ));
+            } else {
+                out.push(format!("{} {}", rng.between(1, 300000), name));
+            }
+        }
+        for d in subdirs {
+            out.push(format!("$ cd {}", d));
+            explore(rng, out, dirs, depth + 1);
+            out.push("$ cd ..".to_owned());
+        }
+    }
+    let mut out = vec!["$ cd /".to_owned()];
+    explore(rng, &mut out, &mut (3 + size * 50), 0);
+    lines(out)
+}
+
+fn day8(rng: &mut Rng, size: usize) -> String {
+    let n = 5 + size * 20;
+    lines((0..n).map(|_| (0..n).map(|_| rng.between(0, 9).to_string()).collect()))
+}
+
+fn day9(rng: &mut Rng, size: usize) -> String {
+    lines(
+        (0..1 + size * 500)
+            .map(|_| format!("{} {}", *rng.pick(b"RLUD") as char, rng.between(1, 20))),
+    )
+}
+
+/// Exactly the 240 cycles of the screen.
+fn day10(rng: &mut Rng) -> String {
+    let mut cycles = 0;
+    let mut prog = Vec::new();
+    while cycles < 240 {
+        if cycles < 239 && rng.chance(0.6) {
+            prog.push(format!("addx {}", rng.between(-15, 15)));
+            cycles += 2;
+        } else {
+            prog.push("noop".to_owned());
+            cycles += 1;
+        }
+    }
+    lines(prog)
+}
+
+/// Divisors are distinct primes, small enough for the worry levels to stay in a `usize`.
+fn day11(rng: &mut Rng, size: usize) -> String {
+    let n = (3 + size).min(8);
+    let mut primes = vec![2, 3, 5, 7, 11, 13, 17, 19, 23];
+    rng.shuffle(&mut primes);
+    let monkeys = (0..n).map(|i| {
+        let items = (0..rng.between(1, 6))
+            .map(|_| rng.between(50, 99).to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let op = match rng.below(3) {
+            0 => "* old".to_owned(),
+            1 => format!("+ {}", rng.between(1, 8)),
+            _ => format!("* {}", rng.between(2, 19)),
+        };
+        let t = (i + 1 + rng.below(n - 1)) % n;
+        let f = loop {
+            let f = rng.below(n);
+            if f != i && f != t {
+                break f;
+            }
+        };
+        format!(
+            "Monkey {}:\n  Starting items: {}\n  Operation: new = old {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}",
+            i, items, op, primes[i], t, f
+        )
+    });
+    monkeys.collect::<Vec<_>>().join("\n\n")
+}
+
+/// A winding path climbing from `S` to `E` one step at a time, in random terrain.
+fn day12(rng: &mut Rng, size: usize) -> String {
+    // long enough for the path to start with an `a` after `S`
+    let (w, h) = (9 + size * 8, 5 + size * 4);
+    let mut map: Vec<Vec<u8>> = (0..h)
+        .map(|_| (0..w).map(|_| *rng.pick(&LETTERS[..26])).collect())
+        .collect();
+    let mut path = Vec::new();
+    for y in (0..h).step_by(2) {
+        let row: Vec<usize> = match (y / 2) % 2 {
+            0 => (0..w).collect(),
+            _ => (0..w).rev().collect(),
+        };
+        if y > 0 {
+            path.push((row[0], y - 1));
+        }
+        path.extend(row.into_iter().map(|x| (x, y)));
+    }
+    for (i, &(x, y)) in path.iter().enumerate() {
+        map[y][x] = b'a' + (i * 25 / (path.len() - 1)) as u8;
+    }
+    let (s, e) = (path[0], path[path.len() - 1]);
+    map[s.1][s.0] = b'S';
+    map[e.1][e.0] = b'E';
+    lines(map.into_iter().map(|r| String::from_utf8(r).unwrap()))
+}
+
+/// Every packet starts with a different number, possibly wrapped in lists, so no two are equal
+/// nor equal to a divider.
+fn day13(rng: &mut Rng, size: usize) -> String {
+    fn value(rng: &mut Rng, depth: usize) -> String {
+        if depth == 0 || rng.chance(0.4) {
+            return rng.between(0, 10).to_string();
+        }
+        let items: Vec<_> = (0..rng.below(5)).map(|_| value(rng, depth - 1)).collect();
+        format!("[{}]", items.join(","))
+    }
+    let pairs = 2 + size * 100;
+    let mut keys: Vec<usize> = (0..2 * pairs + 2).filter(|&k| k != 2 && k != 6).collect();
+    rng.shuffle(&mut keys);
+    let mut packets = keys.into_iter().map(|k| {
+        let mut first = k.to_string();
+        for _ in 0..rng.below(3) {
+            first = format!("[{}]", first);
+        }
+        let rest: String = (0..rng.below(5))
+            .map(|_| format!(",{}", value(rng, 3)))
+            .collect();
+        format!("[{}{}]", first, rest)
+    });
+    let pairs =
+        (0..pairs).map(|_| format!("{}\n{}", packets.next().unwrap(), packets.next().unwrap()));
+    pairs.collect::<Vec<_>>().join("\n\n")
+}
+
+/// Rock paths under the source of sand.
+fn day14(rng: &mut Rng, size: usize) -> String {
+    // the sand piling up on the floor must stay right of x = 0
+    let depth = (10 + size as i64 * 20).min(400);
+    lines((0..2 + size * 20).map(|_| {
+        let mut p = (500 + rng.between(-depth, depth), rng.between(2, depth));
+        let mut points = vec![p];
+        for i in 0..rng.between(1, 4) {
+            let d = rng.between(1, 8) * if rng.chance(0.5) { 1 } else { -1 };
+            match i % 2 {
+                0 => p.0 += d,
+                _ => p.1 = (p.1 + d).clamp(1, depth + 8),
+            }
+            if p != points[points.len() - 1] {
+                points.push(p);
+            }
+        }
+        if points.len() == 1 {
+            points.push((p.0 + 1, p.1));
+        }
+        points
+            .iter()
+            .map(|(x, y)| format!("{},{}", x, y))
+            .collect::<Vec<_>>()
+            .join(" -> ")
+    }))
+}
+
+/// For the default parameters: four sensors at the diagonals of the distress beacon cover the
+/// whole search area but it, smaller ones are sprinkled below the top one.
+fn day15(rng: &mut Rng, size: usize) -> String {
+    const BOUND: i64 = 4000000;
+    let a = rng.between(BOUND / 2, BOUND);
+    let b = (a, rng.between(BOUND - a, a));
+    let mut sensors = Vec::new();
+    for (sx, sy) in [(1, 1), (1, -1), (-1, 1), (-1, -1)] {
+        let s = (b.0 + sx * a, b.1 + sy * a);
+        sensors.push((s, (s.0 + sx * a, s.1 + sy * (a - 1))));
+    }
+    for _ in 0..size * 20 {
+        let s = (rng.between(0, BOUND), rng.between(b.1 - a, BOUND));
+        let dist = (s.0 - b.0).abs() + (s.1 - b.1).abs();
+        if dist == 0 {
+            continue;
+        }
+        let r = rng.between(0, (dist - 1).min(BOUND / 4));
+        let dx = rng.between(-r, r);
+        let dy = (r - dx.abs()) * if rng.chance(0.5) { 1 } else { -1 };
+        sensors.push((s, (s.0 + dx, s.1 + dy)));
+    }
+    rng.shuffle(&mut sensors);
+    lines(sensors.into_iter().map(|(s, c)| {
+        format!(
+            "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+            s.0, s.1, c.0, c.1
+        )
+    }))
+}
+
+/// A connected cave, with at most 15 working valves.
+fn day16(rng: &mut Rng, size: usize) -> String {
+    let n = (8 + size * 6).min(60);
+    let working = (4 + size * 2).min(15);
+    let mut names = Names::new(&LETTERS[26..], 2, &["AA"]);
+    let names: Vec<String> = (0..n)
+        .map(|i| match i {
+            0 => "AA".to_owned(),
+            _ => names.next(rng),
+        })
+        .collect();
+    let mut tunnels = vec![HashSet::new(); n];
+    for i in 1..n {
+        let j = rng.below(i);
+        tunnels[i].insert(j);
+        tunnels[j].insert(i);
+    }
+    for _ in 0..n / 3 {
+        let (i, j) = (rng.below(n), rng.below(n));
+        if i != j {
+            tunnels[i].insert(j);
+            tunnels[j].insert(i);
+        }
+    }
+    let mut rates: Vec<i64> = (0..n)
+        .map(|i| match i > 0 && i <= working {
+            true => rng.between(1, 25),
+            false => 0,
+        })
+        .collect();
+    rng.shuffle(&mut rates[1..]);
+    lines((0..n).map(|i| {
+        let mut to: Vec<&str> = tunnels[i].iter().map(|&j| names[j].as_str()).collect();
+        to.sort();
+        let to = to.join(", ");
+        match tunnels[i].len() {
+            1 => format!(
+                "Valve {} has flow rate={}; tunnel leads to valve {}",
+                names[i], rates[i], to
+            ),
+            _ => format!(
+                "Valve {} has flow rate={}; tunnels lead to valves {}",
+                names[i], rates[i], to
+            ),
+        }
+    }))
+}
+
+fn day17(rng: &mut Rng, size: usize) -> String {
+    (0..10 + size * 1000)
+        .map(|_| *rng.pick(b"<>") as char)
+        .collect()
+}
+
+/// Cubes away from the origin, where the steam starts.
+fn day18(rng: &mut Rng, size: usize) -> String {
+    let n = (4 + size as isize * 4).min(day18::MAX_COORD);
+    let mut cubes = Vec::new();
+    for x in 1..=n {
+        for y in 1..=n {
+            for z in 1..=n {
+                if rng.chance(0.3) {
+                    cubes.push(format!("{},{},{}", x, y, z));
+                }
+            }
+        }
+    }
+    if cubes.is_empty() {
+        cubes.push("1,1,1".to_owned());
+    }
+    rng.shuffle(&mut cubes);
+    lines(cubes)
+}
+
+/// Costs in the ranges of the real blueprints.
+fn day19(rng: &mut Rng, size: usize) -> String {
+    lines((1..=3 + size * 10).map(|id| {
+        format!(
+            "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. Each obsidian robot costs {} ore and {} clay. Each geode robot costs {} ore and {} obsidian.",
+            id,
+            rng.between(2, 4),
+            rng.between(2, 4),
+            rng.between(2, 4),
+            rng.between(5, 20),
+            rng.between(2, 4),
+            rng.between(5, 20),
+        )
+    }))
+}
+
+/// Exactly one 0.
+fn day20(rng: &mut Rng, size: usize) -> String {
+    let mut numbers: Vec<i64> = (0..3 + size * 1000)
+        .map(|_| rng.between(1, 10000) * if rng.chance(0.5) { 1 } else { -1 })
+        .collect();
+    let i = rng.below(numbers.len());
+    numbers[i] = 0;
+    lines(numbers.into_iter().map(|n| n.to_string()))
+}
+
+/// Monkeys yelling numbers computed without remainders, `humn` in one branch of `root` only, and
+/// the branch of `humn` dividing evenly both with its number and the one solving part 2.
+fn day21(rng: &mut Rng, size: usize) -> String {
+    struct Monkeys<'a> {
+        rng: &'a mut Rng,
+        names: Names,
+        jobs: Vec<String>,
+    }
+    impl Monkeys<'_> {
+        fn job(&mut self, name: Option<&str>, job: String) -> String {
+            let name = name.map_or_else(|| self.names.next(self.rng), str::to_owned);
+            self.jobs.push(format!("{}: {}", name, job));
+            name
+        }
+        fn op(&mut self, name: Option<&str>, a: &str, op: char, b: &str) -> String {
+            self.job(name, format!("{} {} {}", a, op, b))
+        }
+        /// A monkey yelling `v`.
+        fn constant(&mut self, v: i64, depth: usize) -> String {
+            if v <= 0 {
+                let a = self.rng.between(1, 20);
+                let (a, b) = (self.constant(a, depth), self.constant(a - v, depth));
+                return self.op(None, &a, '-', &b);
+            }
+            if depth == 0 || self.rng.chance(0.3) {
+                return self.job(None, v.to_string());
+            }
+            let depth = depth - 1;
+            match self.rng.below(4) {
+                0 if v > 1 => {
+                    let a = self.rng.between(1, v - 1);
+                    let (a, b) = (self.constant(a, depth), self.constant(v - a, depth));
+                    self.op(None, &a, '+', &b)
+                }
+                1 if v < 1 << 40 => {
+                    let b = self.rng.between(2, 5);
+                    let (a, b) = (self.constant(v * b, depth), self.constant(b, depth));
+                    self.op(None, &a, '/', &b)
+                }
+                2 => match (2..10).find(|d| v % d == 0 && v > *d) {
+                    Some(d) => {
+                        let (a, b) = (self.constant(d, depth), self.constant(v / d, depth));
+                        self.op(None, &a, '*', &b)
+                    }
+                    None => self.job(None, v.to_string()),
+                },
+                _ => {
+                    let b = self.rng.between(1, 20);
+                    let (a, b) = (self.constant(v + b, depth), self.constant(b, depth));
+                    self.op(None, &a, '-', &b)
+                }
+            }
+        }
+    }
+
+    let mut m = Monkeys {
+        rng,
+        names: Names::new(&LETTERS[..26], 4, &["root", "humn"]),
+        jobs: Vec::new(),
+    };
+    let depth = 2 + size.min(4);
+    // the values of the branch of `humn` in part 1 and part 2
+    let mut v = (m.rng.between(1, 1000), m.rng.between(1, 10000));
+    let mut current = m.job(Some("humn"), v.0.to_string());
+    for _ in 0..3 + size * 10 {
+        let k = m.rng.between(2, 9);
+        let (op, k) = match m.rng.below(4) {
+            0 => ('+', k * 10),
+            1 => ('-', k * 10),
+            2 if v.0.abs().max(v.1.abs()) < 1 << 40 => ('*', k),
+            _ if v.0 % k == 0 && v.1 % k == 0 => ('/', k),
+            _ => ('+', k),
+        };
+        let constant = m.constant(k, depth);
+        let left = op == '/' || m.rng.chance(0.5);
+        v = match (op, left) {
+            ('+', _) => (v.0 + k, v.1 + k),
+            ('-', true) => (v.0 - k, v.1 - k),
+            ('-', false) => (k - v.0, k - v.1),
+            ('*', _) => (v.0 * k, v.1 * k),
+            _ => (v.0 / k, v.1 / k),
+        };
+        current = match left {
+            true => m.op(None, &current, op, &constant),
+            false => m.op(None, &constant, op, &current),
+        };
+    }
+    let other = m.constant(v.1, depth);
+    match m.rng.chance(0.5) {
+        true => m.op(Some("root"), &current, '+', &other),
+        false => m.op(Some("root"), &other, '+', &current),
+    };
+    let mut jobs = m.jobs;
+    rng.shuffle(&mut jobs);
+    lines(jobs)
+}
+
+/// One of the eleven cube nets, turned and flipped, with the start tile open.
+fn day22(rng: &mut Rng, size: usize) -> String {
+    let mut faces: Vec<(i64, i64)> = match rng.below(4) {
+        0 => {
+            let mut f: Vec<_> = (0..4).map(|x| (x, 1)).collect();
+            f.extend([(rng.between(0, 3), 0), (rng.between(0, 3), 2)]);
+            f
+        }
+        1 => vec![
+            (0, 0),
+            (1, 0),
+            (1, 1),
+            (2, 1),
+            (3, 1),
+            (rng.between(1, 3), 2),
+        ],
+        2 => vec![(0, 0), (1, 0), (1, 1), (2, 1), (2, 2), (3, 2)],
+        _ => vec![(0, 0), (1, 0), (2, 0), (2, 1), (3, 1), (4, 1)],
+    };
+    let flip = rng.chance(0.5);
+    for _ in 0..rng.below(4) {
+        faces.iter_mut().for_each(|f| *f = (-f.1, f.0));
+    }
+    for f in faces.iter_mut() {
+        if flip {
+            f.0 = -f.0;
+        }
+    }
+    let (min_x, min_y) = (
+        faces.iter().map(|f| f.0).min().unwrap(),
+        faces.iter().map(|f| f.1).min().unwrap(),
+    );
+    let faces: HashSet<(usize, usize)> = faces
+        .into_iter()
+        .map(|(x, y)| ((x - min_x) as usize, (y - min_y) as usize))
+        .collect();
+    let s = 4 + size * 8;
+    let (w, h) = (
+        faces.iter().map(|f| f.0).max().unwrap() + 1,
+        faces.iter().map(|f| f.1).max().unwrap() + 1,
+    );
+    let mut start = true;
+    let map = (0..h * s).map(|y| {
+        let row: String = (0..w * s)
+            .map(|x| match faces.contains(&(x / s, y / s)) {
+                false => ' ',
+                true if start => {
+                    start = false;
+                    '.'
+                }
+                true if rng.chance(0.1) => '#',
+                true => '.',
+            })
+            .collect();
+        row.trim_end().to_owned()
+    });
+    let map = lines(map);
+    let mut path = rng.between(1, 3 * s as i64).to_string();
+    for _ in 0..10 + size * 200 {
+        path.push(*rng.pick(b"LR") as char);
+        path.push_str(&rng.between(1, 3 * s as i64).to_string());
+    }
+    format!("{}\n\n{}", map, path)
+}
+
+fn day23(rng: &mut Rng, size: usize) -> String {
+    let n = 6 + size * 8;
+    lines((0..n).map(|_| {
+        (0..n)
+            .map(|_| if rng.chance(0.45) { '#' } else { '.' })
+            .collect()
+    }))
+}
+
+/// Openings in the corners like the real valleys, and no blizzard going up or down in their
+/// columns, which would leave the valley.
+fn day24(rng: &mut Rng, size: usize) -> String {
+    let (w, h) = (8 + size * 16, 6 + size * 4);
+    lines((0..h).map(|y| {
+        (0..w)
+            .map(|x| {
+                if y == 0 {
+                    return if x == 1 { '.' } else { '#' };
+                } else if y == h - 1 {
+                    return if x == w - 2 { '.' } else { '#' };
+                } else if x == 0 || x == w - 1 {
+                    return '#';
+                }
+                if !rng.chance(0.3) {
+                    return '.';
+                }
+                match x == 1 || x == w - 2 {
+                    true => *rng.pick(b"<>") as char,
+                    false => *rng.pick(b"<>^v") as char,
+                }
+            })
+            .collect()
+    }))
+}
+
+fn day25(rng: &mut Rng, size: usize) -> String {
+    lines((0..3 + size * 100).map(|_| {
+        let digits = rng.between(1, 20) as u32;
+        to_snafu(rng.between(1, 5i64.pow(digits)) as usize)
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::registry;
+
+    #[test]
+    fn deterministic() {
+        for day in 1..=25 {
+            assert_eq!(generate(day, 1, 1), generate(day, 1, 1));
+            assert_ne!(generate(day, 1, 1), generate(day, 2, 1), "day {}", day);
+        }
+        assert_eq!(generate(26, 1, 1), None);
+    }
+
+    #[test]
+    fn solvable() {
+        for (day, solver) in registry() {
+            for seed in 0..3 {
+                let input = generate(day, seed, 0).unwrap();
+                let parsed = solver
+                    .parse(&input)
+                    .unwrap_or_else(|e| panic!("day {} seed {}: {}\n{}", day, seed, e, input));
+                let mut params = solver.params();
+                if day == 19 {
+                    // 32 minutes take seconds per blueprint
+                    params.set("minutes2", "24").unwrap();
+                }
+                solver.part1(parsed.as_ref(), params.as_ref());
+                solver.part2(parsed.as_ref(), params.as_ref());
+            }
+        }
+    }
+}
@@ -1,8 +1,4 @@
 #![warn(clippy::all)]
-#![feature(drain_filter)]
-#![feature(box_patterns)]
-#![feature(box_syntax)]
-#![feature(is_some_and)]
 
 extern crate aoc_runner;
 #[macro_use]
@@ -25,6 +21,9 @@ pub mod day2;
 pub mod day20;
 pub mod day21;
 pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
 pub mod day3;
 pub mod day4;
 pub mod day5;
@@ -32,7 +31,16 @@ pub mod day6;
 pub mod day7;
 pub mod day8;
 pub mod day9;
-pub mod day23;
-pub mod day24;
-pub mod day25;
+pub mod fuzz;
+pub mod gen;
+pub mod oracle;
+pub mod solution;
+
+/// The year of these puzzles.
+pub const YEAR: u16 = 2022;
+
+/// The version of this crate and a hash of the sources of its solvers, which change whenever
+/// their answers may.
+pub const BUILD: &str = concat!(env!("CARGO_PKG_VERSION"), "+", env!("AOC_SOURCES_HASH"));
+
 aoc_lib! { year = 2022 }
@@ -0,0 +1,410 @@
+//! Slow but straightforward solutions of the days taking shortcuts, to check them on many
+//! generated inputs.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt,
+    ops::Range,
+};
+
+use crate::{
+    day16::{self, Valve},
+    day17,
+    day21::{self, Op},
+    day22::{self, Inst},
+    gen::{self, Rng},
+    solution::registry,
+};
+use aoc_common::{answer::Answer, geom::Dir4, params::Params, simulation::Simulation};
+
+/// Days with an oracle.
+pub const DAYS: [u8; 4] = [16, 17, 21, 22];
+
+/// Answer of the oracle, `None` for a part without oracle. `input` is normalised like the
+/// solver of the day does.
+pub fn oracle(day: u8, part: u8, input: &str, params: &dyn Params) -> Option<Answer> {
+    let input = &registry().get(&day)?.normalise(input);
+    Some(match (day, part) {
+        (16, _) => {
+            let p: &day16::Params = params.as_any().downcast_ref()?;
+            let (valves, _, start) = day16::input_generator(input).ok()?;
+            let best = opened_valves(&valves, start, [p.minutes1, p.minutes2][part as usize - 1]);
+            match part {
+                1 => best.values().max().copied().unwrap_or(0),
+                _ => best
+                    .iter()
+                    .flat_map(|(a, x)| {
+                        best.iter()
+                            .filter(|(b, _)| *a & *b == 0)
+                            .map(move |(_, y)| x + y)
+                    })
+                    .max()
+                    .unwrap_or(0),
+            }
+            .into()
+        }
+        (17, _) => {
+            let p: &day17::Params = params.as_any().downcast_ref()?;
+            let mut game = day17::input_generator(input).ok()?;
+            game.run([p.rocks1, p.rocks2][part as usize - 1]);
+            game.height().into()
+        }
+        (21, 2) => humn(&day21::input_generator(input).ok()?)?.into(),
+        (22, 2) => walk_cube(&day22::input_generator(input).ok()?)?.into(),
+        _ => return None,
+    })
+}
+
+/// Best pressure released for each set of valves opened, trying every order to open them.
+fn opened_valves(valves: &[Valve], start: usize, minutes: usize) -> HashMap<u64, usize> {
+    let index: HashMap<&str, usize> = valves
+        .iter()
+        .enumerate()
+        .map(|(i, v)| (v.name.as_str(), i))
+        .collect();
+    let dist: Vec<Vec<usize>> = (0..valves.len())
+        .map(|from| {
+            let mut dist = vec![usize::MAX; valves.len()];
+            dist[from] = 0;
+            let mut queue = VecDeque::from([from]);
+            while let Some(v) = queue.pop_front() {
+                for t in &valves[v].tunnels {
+                    let t = index[t.as_str()];
+                    if dist[t] == usize::MAX {
+                        dist[t] = dist[v] + 1;
+                        queue.push_back(t);
+                    }
+                }
+            }
+            dist
+        })
+        .collect();
+    let working: Vec<usize> = (0..valves.len()).filter(|&v| valves[v].rate > 0).collect();
+
+    fn visit(
+        at: usize,
+        left: usize,
+        opened: u64,
+        released: usize,
+        ctx: (&[Valve], &[Vec<usize>], &[usize]),
+        best: &mut HashMap<u64, usize>,
+    ) {
+        let e = best.entry(opened).or_insert(0);
+        *e = (*e).max(released);
+        let (valves, dist, working) = ctx;
+        for (bit, &v) in working.iter().enumerate() {
+            let d = dist[at][v];
+            if opened & 1 << bit != 0 || d == usize::MAX || d + 1 >= left {
+                continue;
+            }
+            let left = left - d - 1;
+            let released = released + valves[v].rate * left;
+            visit(v, left, opened | 1 << bit, released, ctx, best);
+        }
+    }
+    let mut best = HashMap::new();
+    visit(start, minutes, 0, 0, (valves, &dist, &working), &mut best);
+    best
+}
+
+/// What a monkey yells when `humn` yells `x`, `None` if a division has a remainder.
+fn yell(monkeys: &HashMap<String, Op>, name: &str, x: isize) -> Option<isize> {
+    if name == "humn" {
+        return Some(x);
+    }
+    let (a, b) = match &monkeys[name] {
+        Op::Shout(n) => return Some(*n),
+        Op::Add(a, b) | Op::Sub(a, b) | Op::Mul(a, b) | Op::Div(a, b) => {
+            (yell(monkeys, a, x)?, yell(monkeys, b, x)?)
+        }
+    };
+    match &monkeys[name] {
+        Op::Add(..) => a.checked_add(b),
+        Op::Sub(..) => a.checked_sub(b),
+        Op::Mul(..) => a.checked_mul(b),
+        _ => (b != 0 && a % b == 0).then(|| a / b),
+    }
+}
+
+/// Smallest number (by absolute value, up to a million) making both sides of `root` equal.
+fn humn(monkeys: &HashMap<String, Op>) -> Option<isize> {
+    let (Op::Add(a, b) | Op::Sub(a, b) | Op::Mul(a, b) | Op::Div(a, b)) = &monkeys["root"] else {
+        return None;
+    };
+    (0..=1000000).flat_map(|x| [x, -x]).find(
+        |&x| matches!((yell(monkeys, a, x), yell(monkeys, b, x)), (Some(a), Some(b)) if a == b),
+    )
+}
+
+type V3 = [i64; 3];
+
+fn add(a: V3, b: V3) -> V3 {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+fn mul(a: V3, k: i64) -> V3 {
+    a.map(|c| c * k)
+}
+
+fn dot(a: V3, b: V3) -> i64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+/// Where a face of the net lies on the cube: its right and down directions and its outward
+/// normal.
+#[derive(Debug, Clone, Copy)]
+struct Face {
+    r: V3,
+    d: V3,
+    n: V3,
+}
+
+/// The face next to `f` in direction `dir` on the net, once folded along their edge.
+fn fold(f: Face, dir: Dir4) -> Face {
+    let Face { r, d, n } = f;
+    match dir {
+        Dir4::Right => Face {
+            r: mul(n, -1),
+            d,
+            n: r,
+        },
+        Dir4::Left => Face {
+            r: n,
+            d,
+            n: mul(r, -1),
+        },
+        Dir4::Down => Face {
+            r,
+            d: mul(n, -1),
+            n: d,
+        },
+        Dir4::Up => Face {
+            r,
+            d: n,
+            n: mul(d, -1),
+        },
+    }
+}
+
+/// Part 2 walking on an actual cube: the net is folded face by face, and a step over an edge is
+/// a step in space.
+fn walk_cube(walker: &day22::Walker) -> Option<usize> {
+    let map = &walker.map;
+    let tiles = map.iter().filter(|(_, &c)| c != ' ').count();
+    let s = (1..=tiles).find(|s| 6 * s * s >= tiles)?;
+    if 6 * s * s != tiles {
+        return None;
+    }
+    let is_face = |(x, y): (usize, usize)| map.get((x * s, y * s)).is_some_and(|&c| c != ' ');
+
+    // unfold the net from the first face, folding along each shared edge
+    let first: (usize, usize) = (map.row(0).position(|&c| c != ' ')? / s, 0);
+    let mut faces = HashMap::from([(
+        first,
+        Face {
+            r: [1, 0, 0],
+            d: [0, 1, 0],
+            n: [0, 0, -1],
+        },
+    )]);
+    let mut queue = VecDeque::from([first]);
+    while let Some((x, y)) = queue.pop_front() {
+        for dir in Dir4::ALL {
+            let delta = dir.delta();
+            let (Some(nx), Some(ny)) =
+                (x.checked_add_signed(delta.x), y.checked_add_signed(delta.y))
+            else {
+                continue;
+            };
+            if is_face((nx, ny)) && !faces.contains_key(&(nx, ny)) {
+                faces.insert((nx, ny), fold(faces[&(x, y)], dir));
+                queue.push_back((nx, ny));
+            }
+        }
+    }
+    if faces.len() != 6 {
+        return None;
+    }
+
+    let s3 = s as i64;
+    // doubled coordinates, so that the centres of the tiles are on integers
+    let to_space = |b: (usize, usize), (i, j): (usize, usize)| {
+        let f = faces[&b];
+        let (i, j) = (i as i64, j as i64);
+        add(
+            mul(f.n, s3),
+            add(mul(f.r, 2 * i + 1 - s3), mul(f.d, 2 * j + 1 - s3)),
+        )
+    };
+    let dir = |f: &Face, m: V3| {
+        [
+            (f.r, Dir4::Right),
+            (f.d, Dir4::Down),
+            (mul(f.r, -1), Dir4::Left),
+            (mul(f.d, -1), Dir4::Up),
+        ]
+        .into_iter()
+        .find(|&(v, _)| v == m)
+        .unwrap()
+        .1
+    };
+    let step = |b: (usize, usize), (i, j): (usize, usize), d: Dir4| {
+        let delta = d.delta();
+        let (ni, nj) = (i as isize + delta.x, j as isize + delta.y);
+        if (0..s as isize).contains(&ni) && (0..s as isize).contains(&nj) {
+            return (b, (ni as usize, nj as usize), d);
+        }
+        let f = faces[&b];
+        let m = match d {
+            Dir4::Right => f.r,
+            Dir4::Down => f.d,
+            Dir4::Left => mul(f.r, -1),
+            Dir4::Up => mul(f.d, -1),
+        };
+        let p = add(to_space(b, (i, j)), add(m, mul(f.n, -1)));
+        let (&nb, nf) = faces.iter().find(|(_, g)| g.n == m).unwrap();
+        let ij = (
+            ((dot(p, nf.r) + s3 - 1) / 2) as usize,
+            ((dot(p, nf.d) + s3 - 1) / 2) as usize,
+        );
+        (nb, ij, dir(nf, mul(f.n, -1)))
+    };
+
+    let (mut b, mut ij, mut d) = (
+        first,
+        (map.row(0).position(|&c| c != ' ')? % s, 0),
+        Dir4::Right,
+    );
+    for inst in &walker.inst {
+        match *inst {
+            Inst::Left => d = d.turn_left(),
+            Inst::Right => d = d.turn_right(),
+            Inst::Move(n) => {
+                for _ in 0..n {
+                    let (nb, nij, nd) = step(b, ij, d);
+                    if map[(nb.0 * s + nij.0, nb.1 * s + nij.1)] == '#' {
+                        break;
+                    }
+                    (b, ij, d) = (nb, nij, nd);
+                }
+            }
+        }
+    }
+    let (col, row) = (b.0 * s + ij.0 + 1, b.1 * s + ij.1 + 1);
+    Some(1000 * row + 4 * col + d as usize)
+}
+
+/// A generated input on which the solver and the oracle give different answers.
+#[derive(Debug, Clone)]
+pub struct Disagreement {
+    pub day: u8,
+    pub part: u8,
+    pub seed: u64,
+    /// Non default parameters.
+    pub params: Vec<(&'static str, String)>,
+    pub solver: Answer,
+    pub oracle: Answer,
+    pub input: String,
+}
+
+impl fmt::Display for Disagreement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "day {} part {} (seed {}", self.day, self.part, self.seed)?;
+        for (name, value) in &self.params {
+            write!(f, ", {}={}", name, value)?;
+        }
+        write!(
+            f,
+            "): the solver says {} but the oracle says {}, on:\n{}",
+            self.solver, self.oracle, self.input
+        )
+    }
+}
+
+/// Parameters for the small generated inputs, which the oracle can solve the slow way.
+fn small_params(day: u8, seed: u64, params: &mut dyn Params) -> Vec<(&'static str, String)> {
+    let mut changed = Vec::new();
+    if day == 17 {
+        let rocks = 2000 + Rng::new(seed).below(3000);
+        params.set("rocks2", &rocks.to_string()).unwrap();
+        changed.push(("rocks2", rocks.to_string()));
+    }
+    changed
+}
+
+/// Compares the solver of `day` to its oracle on the inputs of size `size` generated from each
+/// seed, returns the number of answers compared or the first disagreement.
+pub fn check(day: u8, seeds: Range<u64>, size: usize) -> Result<usize, Box<Disagreement>> {
+    let registry = registry();
+    let Some(solver) = registry.get(&day) else {
+        return Ok(0);
+    };
+    let mut compared = 0;
+    for seed in seeds {
+        let input = gen::generate(day, seed, size).unwrap();
+        let mut params = solver.params();
+        let changed = small_params(day, seed, params.as_mut());
+        let parsed = solver.parse(&input).unwrap();
+        for part in 1..=2 {
+            let Some(expected) = oracle(day, part, &input, params.as_ref()) else {
+                continue;
+            };
+            let answer = match part {
+                1 => solver.part1(parsed.as_ref(), params.as_ref()),
+                _ => solver.part2(parsed.as_ref(), params.as_ref()),
+            };
+            if answer != expected {
+                return Err(Box::new(Disagreement {
+                    day,
+                    part,
+                    seed,
+                    params: changed,
+                    solver: answer,
+                    oracle: expected,
+                    input,
+                }));
+            }
+            compared += 1;
+        }
+    }
+    Ok(compared)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        let day16 = day16::Params::default();
+        let input = include_str!("../../examples/2022/day16-1.txt");
+        assert_eq!(oracle(16, 1, input, &day16), Some(Answer::Int(1651)));
+        assert_eq!(oracle(16, 2, input, &day16), Some(Answer::Int(1707)));
+        let day17 = day17::Params::default();
+        let input = include_str!("../../examples/2022/day17-1.txt");
+        assert_eq!(oracle(17, 1, input, &day17), Some(Answer::Int(3068)));
+        let input = include_str!("../../examples/2022/day21-1.txt");
+        assert_eq!(oracle(21, 2, input, &()), Some(Answer::Int(301)));
+        let input = include_str!("../../examples/2022/day22-1.txt");
+        assert_eq!(oracle(22, 2, input, &()), Some(Answer::Int(5031)));
+    }
+
+    #[test]
+    fn inexact() {
+        // 2 * humn is never 3, so there is no answer rather than the rounded down 1
+        let input = "root: a + b\na: humn * c\nc: 2\nb: 3\nhumn: 1";
+        let solver = &registry()[&21];
+        let parsed = solver.parse(input).unwrap();
+        assert_eq!(oracle(21, 2, input, &()), None);
+        assert_eq!(solver.part2(parsed.as_ref(), &()), Answer::Nothing);
+    }
+
+    #[test]
+    fn differential() {
+        for day in DAYS {
+            if let Err(d) = check(day, 0..50, 0) {
+                panic!("{}", d);
+            }
+        }
+    }
+}
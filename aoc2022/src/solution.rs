@@ -0,0 +1,141 @@
+use std::collections::BTreeMap;
+
+use aoc_common::solution::{solver, Solver};
+
+use crate::*;
+
+/// All the days, indexed by their number.
+pub fn registry() -> BTreeMap<u8, Box<dyn Solver>> {
+    [
+        solver::<day1::Day1>(),
+        solver::<day2::Day2>(),
+        solver::<day3::Day3>(),
+        solver::<day4::Day4>(),
+        solver::<day5::Day5>(),
+        solver::<day6::Day6>(),
+        solver::<day7::Day7>(),
+        solver::<day8::Day8>(),
+        solver::<day9::Day9>(),
+        solver::<day10::Day10>(),
+        solver::<day11::Day11>(),
+        solver::<day12::Day12>(),
+        solver::<day13::Day13>(),
+        solver::<day14::Day14>(),
+        solver::<day15::Day15>(),
+        solver::<day16::Day16>(),
+        solver::<day17::Day17>(),
+        solver::<day18::Day18>(),
+        solver::<day19::Day19>(),
+        solver::<day20::Day20>(),
+        solver::<day21::Day21>(),
+        solver::<day22::Day22>(),
+        solver::<day23::Day23>(),
+        solver::<day24::Day24>(),
+        solver::<day25::Day25>(),
+    ]
+    .into_iter()
+    .map(|s| (s.day(), s))
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::answer::Answer;
+    #[test]
+    fn all_days() {
+        let r = registry();
+        assert_eq!(
+            r.keys().copied().collect::<Vec<_>>(),
+            (1..=25).collect::<Vec<_>>()
+        );
+    }
+    #[test]
+    fn erased() {
+        let r = registry();
+        let (p1, p2) = r[&1].solve("1000\n2000\n\n4000\n\n500").unwrap();
+        assert_eq!((p1, p2), (Answer::Int(4000), Answer::Int(7500)));
+    }
+    #[test]
+    fn params() {
+        let r = registry();
+        let input = r[&15]
+            .parse("Sensor at x=8, y=7: closest beacon is at x=2, y=10")
+            .unwrap();
+        let mut params = r[&15].params();
+        params.set("row", "10").unwrap();
+        assert_eq!(
+            r[&15].part1(input.as_ref(), params.as_ref()),
+            Answer::Int(12)
+        );
+        assert!(r[&1].params().set("row", "10").is_err());
+    }
+    #[test]
+    fn params_without_answer() {
+        let r = registry();
+        let input = r[&7].parse("$ cd /\n$ ls\n10 a").unwrap();
+        let mut params = r[&7].params();
+        params.set("update_size", "100").unwrap();
+        assert_eq!(
+            r[&7].part2(input.as_ref(), params.as_ref()),
+            Answer::Int(10)
+        );
+        params.set("disk_size", "50").unwrap();
+        assert_eq!(
+            r[&7].part2(input.as_ref(), params.as_ref()),
+            Answer::Nothing
+        );
+        let input = r[&17].parse(">").unwrap();
+        let mut params = r[&17].params();
+        params.set("rocks2", &usize::MAX.to_string()).unwrap();
+        assert_eq!(
+            r[&17].part2(input.as_ref(), params.as_ref()),
+            Answer::Nothing
+        );
+        let input = r[&20].parse("1\n0\n-3").unwrap();
+        let mut params = r[&20].params();
+        params.set("key", &isize::MAX.to_string()).unwrap();
+        assert_eq!(
+            r[&20].part2(input.as_ref(), params.as_ref()),
+            Answer::Nothing
+        );
+        let input = r[&15]
+            .parse("Sensor at x=8, y=7: closest beacon is at x=2, y=10\nSensor at x=8, y=-7: closest beacon is at x=2, y=-10")
+            .unwrap();
+        let mut params = r[&15].params();
+        for row in [isize::MAX, isize::MIN] {
+            params.set("row", &row.to_string()).unwrap();
+            assert_eq!(
+                r[&15].part1(input.as_ref(), params.as_ref()),
+                Answer::Nothing
+            );
+        }
+        let input = r[&16]
+            .parse("Valve AA has flow rate=0; tunnel leads to valve BB\nValve BB has flow rate=9; tunnel leads to valve AA")
+            .unwrap();
+        let mut params = r[&16].params();
+        assert_eq!(
+            r[&16].part1(input.as_ref(), params.as_ref()),
+            Answer::Int(252)
+        );
+        for minutes in ["0", "10000"] {
+            params.set("minutes1", minutes).unwrap();
+            assert_eq!(
+                r[&16].part1(input.as_ref(), params.as_ref()),
+                Answer::Nothing
+            );
+        }
+    }
+    #[test]
+    fn variants() {
+        let r = registry();
+        let input = r[&8].parse("30373\n25512\n65332\n33549\n35390").unwrap();
+        assert_eq!(r[&8].variants(1), ["fast"]);
+        assert_eq!(
+            r[&8].variant(2, "fast", input.as_ref(), &()),
+            Some(Answer::Int(8))
+        );
+        assert_eq!(r[&8].variant(2, "slow", input.as_ref(), &()), None);
+        assert!(r[&1].variants(1).is_empty());
+    }
+}
@@ -0,0 +1,64 @@
+use std::{fs, path::Path};
+
+use aoc2022::solution::registry;
+
+fn expected(day: u8) -> [String; 2] {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let answers: toml::Table = fs::read_to_string(root.join("../answers/2022.toml"))
+        .unwrap()
+        .parse()
+        .unwrap();
+    let day = &answers[&format!("day{}", day)];
+    ["part1", "part2"].map(|p| match &day[p] {
+        toml::Value::String(s) => s.clone(),
+        v => v.to_string(),
+    })
+}
+
+fn check(day: u8) {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let input = fs::read_to_string(root.join(format!("../input/2022/day{}.txt", day))).unwrap();
+    let solver = &registry()[&day];
+    let input = solver.parse(&input).unwrap();
+    let params = solver.params();
+    let answers = [
+        solver.part1(input.as_ref(), params.as_ref()).to_string(),
+        solver.part2(input.as_ref(), params.as_ref()).to_string(),
+    ];
+
+    let mismatches: Vec<String> = answers
+        .iter()
+        .zip(expected(day))
+        .enumerate()
+        .filter(|(_, (got, expected))| *got != expected)
+        .map(|(i, (got, expected))| {
+            format!(
+                "day {} part {}: expected {:?}, got {:?}",
+                day,
+                i + 1,
+                expected,
+                got
+            )
+        })
+        .collect();
+    assert!(mismatches.is_empty(), "{}", mismatches.join("\n"));
+}
+
+macro_rules! days {
+    ($($day:ident => $n:literal),* $(,)?) => {
+        $(
+            #[test]
+            fn $day() {
+                check($n)
+            }
+        )*
+    };
+}
+
+days! {
+    day1 => 1, day2 => 2, day3 => 3, day4 => 4, day5 => 5,
+    day6 => 6, day7 => 7, day8 => 8, day9 => 9, day10 => 10,
+    day11 => 11, day12 => 12, day13 => 13, day14 => 14, day15 => 15,
+    day16 => 16, day17 => 17, day18 => 18, day19 => 19, day20 => 20,
+    day21 => 21, day22 => 22, day23 => 23, day24 => 24, day25 => 25,
+}
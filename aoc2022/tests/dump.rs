@@ -0,0 +1,40 @@
+//! Needs the `serde` feature, which the runner turns on for the whole workspace.
+#![cfg(feature = "serde")]
+
+use std::fs;
+
+use aoc2022::solution::registry;
+use serde_json::json;
+
+#[test]
+fn every_day() {
+    for (day, solver) in registry() {
+        let input = fs::read_to_string(format!("../examples/2022/day{}-1.txt", day)).unwrap();
+        let input = solver.parse(&input).unwrap();
+        let json = solver.to_json(input.as_ref());
+        assert!(json.is_ok(), "day {}: {}", day, json.unwrap_err());
+    }
+}
+
+#[test]
+fn values() {
+    let registry = registry();
+    let json = |day: u8, input: &str| {
+        let solver = &registry[&day];
+        solver
+            .to_json(solver.parse(input).unwrap().as_ref())
+            .unwrap()
+    };
+    assert_eq!(
+        json(2, "A Y\nC Z"),
+        json!([["Rock", "Y"], ["Scissors", "Z"]])
+    );
+    assert_eq!(
+        json(18, "2,2,2\n1,2,2"),
+        json!([{ "x": 1, "y": 2, "z": 2 }, { "x": 2, "y": 2, "z": 2 }])
+    );
+    assert_eq!(
+        json(17, "<>"),
+        json!({ "jets": ["Left", "Right"], "height": 0, "rocks": 0, "jet": 0 })
+    );
+}
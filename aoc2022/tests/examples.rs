@@ -0,0 +1,95 @@
+//! The examples of the puzzles, one test per file of `examples/`.
+//!
+//! `examples/2022/dayN-K.txt` is the K-th example of day N, and `dayN-K.toml` next to it has
+//! its expected answers: `part1` and `part2`, either one can be left out, the parameters to
+//! change in a `[params]` table, and an `ignore` reason for the examples too slow to run
+//! every time.
+//!
+//! Each example is also solved as saved by a Windows editor, and streamed for the days which can
+//! be.
+
+use std::{fs, path::Path};
+
+use aoc2022::solution::registry;
+
+/// A TOML value as the text an answer is compared with, or a parameter is parsed from.
+fn text(v: &toml::Value) -> String {
+    match v {
+        toml::Value::String(s) => s.clone(),
+        toml::Value::Array(a) => a.iter().map(text).collect::<Vec<_>>().join(","),
+        v => v.to_string(),
+    }
+}
+
+/// With a byte order mark, `\r\n` line endings and a trailing one.
+fn windows(input: &str) -> String {
+    format!("\u{feff}{}", input.replace('\n', "\r\n"))
+}
+
+fn check(day: u8, name: &str) {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../examples");
+    let input = fs::read_to_string(root.join(format!("{}.txt", name))).unwrap();
+    let expected: toml::Table = fs::read_to_string(root.join(format!("{}.toml", name)))
+        .unwrap()
+        .parse()
+        .unwrap();
+
+    let solver = &registry()[&day];
+    let mut params = solver.params();
+    if let Some(overrides) = expected.get("params") {
+        for (param, value) in overrides.as_table().unwrap() {
+            params.set(param, &text(value)).unwrap();
+        }
+    }
+    let windows = windows(&input);
+    let mut answers = vec![];
+    for (how, input) in [("", &input), (" from windows", &windows)] {
+        let input = solver.parse(input).unwrap();
+        let part1 = solver.part1(input.as_ref(), params.as_ref());
+        let part2 = solver.part2(input.as_ref(), params.as_ref());
+        answers.push((how.to_owned(), "part1", part1));
+        answers.push((how.to_owned(), "part2", part2));
+        for part in [1, 2] {
+            for name in solver.variants(part) {
+                let got = solver.variant(part, name, input.as_ref(), params.as_ref());
+                answers.push((
+                    format!("{} with {}", how, name),
+                    ["part1", "part2"][part as usize - 1],
+                    got.unwrap(),
+                ));
+            }
+        }
+    }
+    if let Some(streamed) = solver.stream(&mut windows.as_bytes(), params.as_ref()) {
+        let (part1, part2) = streamed.unwrap();
+        let how = " streamed from windows";
+        answers.push((how.to_owned(), "part1", part1));
+        answers.push((how.to_owned(), "part2", part2));
+    }
+    let mismatches: Vec<String> = answers
+        .into_iter()
+        .filter_map(|(how, part, got)| {
+            let expected = text(expected.get(part)?);
+            let got = got.to_string();
+            (got != expected).then(|| {
+                format!(
+                    "{}{} {}: expected {:?}, got {:?}",
+                    name, how, part, expected, got
+                )
+            })
+        })
+        .collect();
+    assert!(mismatches.is_empty(), "{}", mismatches.join("\n"));
+}
+
+macro_rules! example {
+    ($(#[$m:meta])* $test:ident, $day:literal, $name:literal) => {
+        $(#[$m])*
+        #[test]
+        fn $test() {
+            check($day, $name)
+        }
+    };
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
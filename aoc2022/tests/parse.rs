@@ -0,0 +1,146 @@
+use aoc2022::solution::registry;
+
+fn err(day: u8, input: &str) -> String {
+    registry()[&day].parse(input).unwrap_err().to_string()
+}
+
+#[test]
+fn malformed_inputs() {
+    assert_eq!(
+        err(2, "A Y\nB W"),
+        "day 2, line 2, column 3: expected `X`, `Y` or `Z`"
+    );
+    assert_eq!(
+        err(2, "A Y\nBZ"),
+        "day 2, line 2, column 2: expected a space"
+    );
+    assert_eq!(
+        err(2, "A Y\nB Zebra"),
+        "day 2, line 2, column 4: expected the end of the line"
+    );
+    assert_eq!(
+        err(5, "[A]\n 1 \n\nmove 1 from 1 to 2"),
+        "day 5, line 4, column 1: expected a stack between 1 and 1"
+    );
+    assert_eq!(
+        err(
+            11,
+            "Monkey 0:\n  Starting items: 79, 98\n  Operation: new = old * 19"
+        ),
+        "day 11, line 3, column 28: expected `  Test: divisible by N`"
+    );
+    assert_eq!(
+        err(13, "[1,[2]\n[3]"),
+        "day 13, line 1, column 7: expected `,` or `]`"
+    );
+    assert_eq!(
+        err(16, "Valve AA has flow rate=0; tunnels lead to valves BB"),
+        "day 16, line 1, column 50: expected a known valve"
+    );
+}
+
+#[test]
+fn inputs_without_answer() {
+    assert_eq!(
+        err(3, "abcd"),
+        "day 3, line 1, column 1: expected an item in both compartments"
+    );
+    assert_eq!(
+        err(5, "[A]    \n 1   2 \n\nmove 2 from 1 to 2"),
+        "day 5, line 4, column 1: expected at most 1 crates to move"
+    );
+    assert_eq!(
+        err(4, "2-4,6-8\n2-3,5-4"),
+        "day 4, line 2, column 7: expected a section not before the start of the range"
+    );
+    assert_eq!(
+        err(12, "Sbz\nbcE"),
+        "day 12, line 1, column 1: expected an `S` square from which `E` can be reached"
+    );
+    let monkey = |n: usize, div: usize| {
+        format!(
+            "Monkey {}:\n  Starting items: 1\n  Operation: new = old + 1\n  Test: divisible by {}\n    If true: throw to monkey 0\n    If false: throw to monkey 0",
+            n, div
+        )
+    };
+    assert_eq!(
+        err(11, &monkey(0, 2)),
+        "day 11, line 6, column 32: expected a second monkey"
+    );
+    assert_eq!(
+        err(11, &format!("{}\n\n{}", monkey(0, 65536), monkey(1, 65537))),
+        "day 11, line 11, column 22: expected divisors with a product up to 2^32"
+    );
+    let working: String = ('A'..='P')
+        .map(|c| format!("\nValve B{} has flow rate=1; tunnel leads to valve AA", c))
+        .collect();
+    assert_eq!(
+        err(
+            16,
+            &format!(
+                "Valve AA has flow rate=0; tunnel leads to valve BA{}",
+                working
+            )
+        ),
+        "day 16, line 17, column 24: expected at most 15 valves with a flow rate"
+    );
+    assert_eq!(err(17, ""), "day 17, line 1, column 1: expected `<` or `>`");
+    assert_eq!(
+        err(18, "1,1,1\n1,-2,1"),
+        "day 18, line 2, column 3: expected a coordinate between 0 and 99"
+    );
+    assert_eq!(
+        err(18, "1,1,100"),
+        "day 18, line 1, column 5: expected a coordinate between 0 and 99"
+    );
+    assert_eq!(err(20, "1\n2"), "day 20, line 2, column 2: expected a `0`");
+    assert_eq!(
+        err(21, "root: 5\nhumn: 1"),
+        "day 21, line 1, column 7: expected `NAME OP NAME` for `root`"
+    );
+    assert_eq!(
+        err(21, "root: a + b\na: 1\nb: 2"),
+        "day 21, line 3, column 5: expected a `humn` monkey"
+    );
+    assert_eq!(
+        err(21, "root: a + humn\na: b * 2\nb: a - 1\nhumn: 3"),
+        "day 21, line 3, column 4: expected a monkey no other monkey waits for"
+    );
+    assert_eq!(
+        err(22, "..\n..\n\n1"),
+        "day 22, line 1, column 1: expected the net of a cube"
+    );
+    assert_eq!(
+        err(23, "..\n.."),
+        "day 23, line 2, column 3: expected an elf"
+    );
+    assert_eq!(
+        err(24, "#.#\n#^#\n#.#"),
+        "day 24, line 2, column 2: expected no `^` or `v` in the columns of the openings"
+    );
+    assert_eq!(
+        err(25, "-1"),
+        "day 25, line 1, column 1: expected a positive number"
+    );
+}
+
+#[test]
+fn trailing_whitespace() {
+    let registry = registry();
+    let solve = |day: u8, input: &str| registry[&day].solve(input).unwrap();
+    assert_eq!(
+        solve(1, "1000 \n2000\t\n\n3000  \n \n"),
+        solve(1, "1000\n2000\n\n3000")
+    );
+    // the spaces ending the first line of day 5 are part of the drawing
+    assert_eq!(
+        solve(
+            5,
+            "    [D]    \n[N] [C]    \n 1   2   3 \n\nmove 1 from 2 to 3 \n"
+        ),
+        solve(
+            5,
+            "    [D]    \n[N] [C]    \n 1   2   3 \n\nmove 1 from 2 to 3"
+        )
+    );
+}
@@ -0,0 +1,26 @@
+use aoc2022::solution::registry;
+use aoc_common::results::{run_day, to_json, to_markdown};
+use serde_json::json;
+
+#[test]
+fn outputs() {
+    let solver = &registry()[&1];
+    let results = [run_day(
+        2022,
+        solver.as_ref(),
+        "1000\n2000\n\n4000\n\n500",
+        solver.params().as_ref(),
+    )
+    .unwrap()];
+    let json = to_json(&results);
+    assert_eq!(
+        (&json[0]["part1"], &json[0]["part2"]),
+        (&json!(4000), &json!(7500))
+    );
+    let md = to_markdown(&results);
+    assert!(md
+        .lines()
+        .nth(2)
+        .unwrap()
+        .starts_with("| 2022 | 1 | `4000` | `7500` |"));
+}
@@ -0,0 +1,61 @@
+use aoc2022::{day14, day17, day23, day24, day9};
+use aoc_common::{geom::Point2, simulation::Simulation, viz::Draw};
+
+#[test]
+fn pause_and_resume() {
+    let map = day14::input_generator("498,4 -> 498,6 -> 496,6\n503,4 -> 502,4 -> 502,9 -> 494,9")
+        .unwrap();
+    let mut sand = day14::Sand::new(map, false);
+    assert_eq!(sand.run(5), 5);
+    let snapshot = sand.snapshot();
+    let grid = sand.state().clone();
+    assert_eq!(sand.run_until(|_| false), 19);
+    assert!(!sand.step());
+    sand.restore(snapshot);
+    assert_eq!(sand.state(), &grid);
+    assert_eq!(sand.run_until(|_| false), 19);
+
+    let mut elves =
+        day23::Elves::new(day23::input_generator(".....\n..##.\n..#..\n.....\n..##.").unwrap());
+    assert_eq!(elves.run(2), 2);
+    let (snapshot, grid) = (elves.snapshot(), elves.state().clone());
+    assert_eq!(elves.run_until(|_| false), 1);
+    assert_eq!(elves.state().iter().filter(|(_, &e)| e).count(), 5);
+    elves.restore(snapshot);
+    assert_eq!(elves.state(), &grid);
+    // on the edge, but the grid is not padded when no elf moves
+    let mut elf = day23::Elves::new(day23::input_generator("#").unwrap());
+    assert!(!elf.step());
+    assert_eq!(elf.state().width(), 1);
+
+    let moves = day9::input_generator("R 4\nU 4").unwrap();
+    let mut rope = day9::Rope::new(&moves, 1);
+    assert_eq!(rope.run_until(|knots| knots[0].x == 4), 4);
+    assert_eq!(rope.state(), [Point2::new(4, 0), Point2::new(3, 0)]);
+    assert_eq!(rope.run_until(|_| false), 4);
+}
+
+#[test]
+fn endless() {
+    let mut game = day17::input_generator(">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>").unwrap();
+    assert_eq!(game.run_until(|g| g.height() >= 17), 9);
+    let snapshot = game.snapshot();
+    assert_eq!(game.run(100), 100);
+    game.restore(snapshot);
+    assert_eq!(game.height(), 17);
+    assert_eq!(game.run(2022 - 9), 2013);
+    assert_eq!(game.height(), 3068);
+
+    let mut valley =
+        day24::input_generator("#.######\n#>>.<^<#\n#.<..<<#\n#>v.><>#\n#<^v^^>#\n######.#")
+            .unwrap();
+    let start = valley.draw();
+    let snapshot = valley.snapshot();
+    assert_eq!(valley.run(5), 5);
+    assert_ne!(valley.draw(), start);
+    valley.restore(snapshot);
+    assert_eq!(valley.draw(), start);
+    // the blizzards are back where they started once they crossed the 6 by 4 valley
+    assert_eq!(valley.run(12), 12);
+    assert_eq!(valley.draw(), start);
+}
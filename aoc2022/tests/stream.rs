@@ -0,0 +1,68 @@
+use std::io::{self, BufReader, Read};
+
+use aoc2022::{day6, gen, solution::registry};
+
+/// Days solved as their input is read.
+const DAYS: [u8; 5] = [1, 6, 9, 10, 20];
+
+#[test]
+fn same_answers() {
+    let registry = registry();
+    for (&day, solver) in &registry {
+        let params = solver.params();
+        let streamed = solver.stream(&mut "".as_bytes(), params.as_ref());
+        assert_eq!(streamed.is_some(), DAYS.contains(&day), "day {}", day);
+    }
+    for day in DAYS {
+        let solver = &registry[&day];
+        let params = solver.params();
+        for seed in 0..5 {
+            let input = gen::generate(day, seed, 2).unwrap();
+            // with the trailing newline the runner trims
+            let reader = format!("{}\n", input);
+            let streamed = solver.stream(&mut reader.as_bytes(), params.as_ref());
+            assert_eq!(
+                streamed.unwrap().unwrap(),
+                solver.solve(&input).unwrap(),
+                "day {}, seed {}",
+                day,
+                seed
+            );
+        }
+    }
+}
+
+#[test]
+fn same_errors() {
+    let registry = registry();
+    for (day, input) in [
+        (1, "1\n\n2\n\n\n3"),
+        (1, "1\n2x"),
+        (6, "abc\nd"),
+        (6, "abcD"),
+        (9, "R 1\nX 2"),
+        (10, "noop\naddx"),
+        (20, "1\n0\n0"),
+        (20, "1\n2"),
+        (20, "0"),
+    ] {
+        let solver = &registry[&day];
+        let streamed = solver.stream(&mut input.as_bytes(), solver.params().as_ref());
+        assert_eq!(
+            streamed.unwrap().unwrap_err().to_string(),
+            solver.parse(input).unwrap_err().to_string(),
+            "day {}, {:?}",
+            day,
+            input
+        );
+    }
+}
+
+#[test]
+fn unbuffered() {
+    // far more than is ever in memory at once
+    let letters = io::repeat(b'a').take(16 << 20);
+    let input = BufReader::new(letters.chain("bcdefghijklmnop".as_bytes()));
+    let n = 16 << 20;
+    assert_eq!(day6::stream(input).unwrap(), (Some(n + 3), Some(n + 13)));
+}
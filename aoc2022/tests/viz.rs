@@ -0,0 +1,25 @@
+use aoc2022::day14;
+use aoc_common::viz::record;
+
+#[test]
+fn sand_frames() {
+    let map = day14::input_generator("498,4 -> 498,6 -> 496,6\n503,4 -> 502,4 -> 502,9 -> 494,9")
+        .unwrap();
+    let mut frames = Vec::new();
+    let steps = record(&mut day14::Sand::new(map, false), &mut frames, 100).unwrap();
+    assert_eq!((steps, frames.len()), (24, 25));
+    assert_eq!(
+        frames[24].to_string(),
+        ".......+....
+............
+.......o....
+......ooo...
+.....#ooo##.
+....o#ooo#..
+...###ooo#..
+.....oooo#..
+..o.ooooo#..
+.#########..
+............"
+    );
+}